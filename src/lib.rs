@@ -47,16 +47,45 @@
 //! * **actix_extras** Enhances actix-web intgration with being able to parse some documentation
 //!   from actix web macro attributes and types. See [`utoipa::path(...)`][path] for more details.
 //! * **debug** Add extra traits such as debug traits to openapi definitions and elsewhere.
-//! * **chrono_types** Add support for [chrono](https://crates.io/crates/chrono) `DateTime`, `Date` and `Duration` types. By default these types
+//! * **chrono_types** Add support for [chrono](https://crates.io/crates/chrono) `DateTime`, `Date`, `NaiveDate`,
+//!   `NaiveDateTime`, `NaiveTime`, `Duration` and `Weekday` types. By default these types
 //!   are parsed to `string` types without
 //!   additional format. If you want to have formats added to the types use _chrono_with_format_ feature.
-//!   This is useful because OpenAPI 3.1 spec does not have date-time formats.
+//!   This is useful because OpenAPI 3.1 spec does not have date-time formats. `Weekday` is documented with
+//!   its serialized values `Mon` to `Sun` as enum values.
 //! * **chrono_types_with_format** Add support to [chrono](https://crates.io/crates/chrono) types described above with additional `format`
-//!   information type. `date-time` for `DateTime` and `date` for `Date` according
-//!   [RFC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14) as `ISO-8601`.
+//!   information type. `date-time` for `DateTime` and `NaiveDateTime`, `date` for `Date` and `NaiveDate`, `time` for
+//!   `NaiveTime` according [RFC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14) as `ISO-8601`
+//!   and `duration` for `Duration` as `ISO-8601` duration.
+//! * **time** Add support for [time](https://crates.io/crates/time) `OffsetDateTime`, `PrimitiveDateTime`, `Date`, `Time`
+//!   and `Duration` types. `date-time` format is added for `OffsetDateTime` and `PrimitiveDateTime`, `date` for `Date`
+//!   and `time` for `Time`. `Duration` is parsed to `string` without format.
+//! * **uuid** Add support for [uuid](https://crates.io/crates/uuid) `Uuid` type. It is parsed to `string` with `uuid` format.
+//! * **url** Add support for [url](https://crates.io/crates/url) `Url` type. It is parsed to `string` with `uri` format.
+//! * **bytes** Add support for [bytes](https://crates.io/crates/bytes) `Bytes` type. It is parsed to array of integers
+//!   the same way as `Vec<u8>` because that is how it is serialized to JSON. Fields serialized as strings can be
+//!   documented with `format = Binary` or `format = Byte`, see [`Component`][component_derive] derive.
+//! * **indexmap** Add support for [indexmap](https://crates.io/crates/indexmap) `IndexMap` and `IndexSet` types.
+//!   `IndexMap` is parsed to object and `IndexSet` to array with unique items the same way as `HashMap` and `HashSet`.
+//! * **json_value** Add support for [serde_json](https://crates.io/crates/serde_json) `Value` type. It is parsed to free-form
+//!   component `{}` which accepts any JSON value. Enables **json** feature.
+//! * **openapi_31** Document tuple types additionally with OpenAPI 3.1 `prefixItems` describing the type of each
+//!   position. The version of the document can be set with [`OpenApiVersion`][openapi_version].
 //! * **decimal** Add support for [rust_decimal](https://crates.io/crates/rust_decimal) `Decimal` type. **By default**
 //!   it is interpreted as `String`. If you wish to change the format you need to override the type.
 //!   See the `value_type` in [component derive docs][component_derive].
+//! * **usize_format_int32** Add `int32` format to `usize`, `isize`, `NonZeroUsize` and `NonZeroIsize` types.
+//!   By default these types have no format since their size depends on the target platform.
+//! * **usize_format_int64** Add `int64` format to `usize`, `isize`, `NonZeroUsize` and `NonZeroIsize` types.
+//!   Takes precedence over **usize_format_int32** when both features are enabled.
+//! * **namespaced_components** Name derived components after the module path where they are defined instead of
+//!   the type name alone. E.g. `Pet` in `api::v2` module is named `api.v2.Pet` instead of `Pet` regardless of how the
+//!   type is written in `components(...)`, `body = ...`, `request_body = ...`, `params(...)` or in the fields. The crate
+//!   name is not part of the name. This allows same named types from different modules to be used as components.
+//! * **validation** Add [`validate`][validate] to validate JSON values against components e.g. to assert in tests
+//!   that responses of the handlers conform to the documented components. Enables **json** feature.
+//! * **proptest** Add [proptest](https://crates.io/crates/proptest) [`strategy`][strategy] generating JSON values of
+//!   components e.g. to fuzz the handlers with valid request bodies. Enables **json** feature.
 //!
 //! # Install
 //!
@@ -181,17 +210,50 @@
 //! * See how to serve OpenAPI doc via Swagger UI check [`utoipa-swagger-ui`][utoipa_swagger] crate for more details.
 //! * Browse to [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more comprehensinve examples.
 //! * Modify generated OpenAPI at runtime check [`Modify`] trait for more details.
+//! * Generate missing request body and response examples with [`FillExamples`][fill_examples] modifier.
 //! * More about OpenAPI security in [security documentation][security].
 //!
 //! [path]: attr.path.html
 //!
 //! [security]: openapi/security/index.html
 //! [component_derive]: derive.Component.html
+//! [openapi_version]: openapi/enum.OpenApiVersion.html
+//! [validate]: openapi/validation/fn.validate.html
+//! [fill_examples]: openapi/example/struct.FillExamples.html
+//! [strategy]: openapi/proptest/fn.strategy.html
 
 pub mod openapi;
+mod partial_component;
+
+use std::{borrow::Cow, marker::PhantomData};
 
 pub use utoipa_gen::*;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use super::partial_component::{
+        component_ref, default_content_type, namespaced_component_name, type_component_name,
+        ComponentOf, FieldAttributes, ResolveComponentRef, ResolveInlineComponent,
+        ResolveInlinePartialComponent, ResolvePartialComponent, ResolveRef,
+    };
+
+    #[cfg(feature = "serde_json")]
+    pub use super::partial_component::serialize_default;
+
+    use std::borrow::Cow;
+
+    use super::{openapi, Component, Create, Read, SplitReadWrite};
+
+    /// Components of the models derived from `T` with `#[component(split_read_write)]`.
+    pub fn split_read_write_components<T: SplitReadWrite>(
+    ) -> Vec<(Cow<'static, str>, openapi::schema::Component)> {
+        vec![
+            (Create::<T>::model_name(), Create::<T>::component()),
+            (Read::<T>::model_name(), Read::<T>::component()),
+        ]
+    }
+}
+
 /// Trait for implementing OpenAPI specification in Rust.
 ///
 /// This trait is derivable and can be used with `#[derive]` attribute. The derived implementation
@@ -297,11 +359,159 @@ pub trait OpenApi {
 ///             .into()
 ///     }
 /// }
+///
+/// impl utoipa::PartialComponent for Pet {
+///     fn partial_component() -> utoipa::openapi::schema::Component {
+///         utoipa::openapi::Ref::from_component_name("Pet").into()
+///     }
+///
+///     fn inline_partial_component() -> utoipa::openapi::schema::Component {
+///         <Self as utoipa::Component>::component()
+///     }
+/// }
 /// ```
+///
+/// Derive also implements [`PartialComponent`] for the type which allows it to be used in other
+/// components e.g. as items of `Vec<Pet>`. Manual implementations should implement it as well.
 pub trait Component {
     fn component() -> openapi::schema::Component;
+
+    /// Optional name of the component in [`Components`][components]. The name is also used in
+    /// the references to the component. When `None` the component is registered and referenced
+    /// with the name of the type resolved from [`std::any::type_name`] which with
+    /// **namespaced_components** feature includes the module path of the type.
+    ///
+    /// Derive implements this with the name of the type, the name given with
+    /// `#[component(as = ...)]` attribute or with **namespaced_components** feature with the
+    /// module path of the type.
+    ///
+    /// [components]: openapi/schema/struct.Components.html
+    fn component_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// Additional components derived from this component which are registered to
+    /// [`Components`][components] together with this component. Returns tuples of component
+    /// name and component.
+    ///
+    /// This is implemented by derive when `#[component(split_read_write)]` attribute is used.
+    ///
+    /// [components]: openapi/schema/struct.Components.html
+    fn derived_components() -> Vec<(Cow<'static, str>, openapi::schema::Component)> {
+        Vec::new()
+    }
+}
+
+/// Trait for types which can be used as part of other components e.g. as a field type of
+/// [`Component`] or as a request or response body type.
+///
+/// The trait is implemented for Rust primitive types, [`String`], tuples and standard library
+/// containers such as [`Vec`], slices, [`Option`], [`Box`] and maps. Containers are documented
+/// with the [`PartialComponent`] of their items thus the items must implement this trait as well.
+/// Derive macros resolve field and body types with this trait thus type aliases like
+/// `type PetIds = Vec<u64>;` are documented by the actual type.
+///
+/// Types deriving [`Component`] implement this trait with a [`Ref`][ref] to the component thus
+/// they can be used as items of containers e.g. `Vec<Pet>`. Types which implement [`Component`]
+/// manually should implement this trait as well. Other types used directly as field or body
+/// types are referenced by their name as reusable components. Generic type parameters which are
+/// used as items of containers must be bound with `PartialComponent` e.g.
+/// `struct Page<T: PartialComponent> { items: Vec<T> }`.
+///
+/// # Examples
+///
+/// Implement `PartialComponent` for a newtype that should be documented inline as a string.
+/// ```rust
+/// # use utoipa::PartialComponent;
+/// struct Email(String);
+///
+/// impl PartialComponent for Email {
+///     fn partial_component() -> utoipa::openapi::schema::Component {
+///         utoipa::openapi::PropertyBuilder::new()
+///             .component_type(utoipa::openapi::ComponentType::String)
+///             .format(Some(utoipa::openapi::ComponentFormat::Password))
+///             .into()
+///     }
+/// }
+/// ```
+///
+/// [ref]: openapi/schema/struct.Ref.html
+pub trait PartialComponent {
+    fn partial_component() -> openapi::schema::Component;
+
+    /// Component used when the type is inlined with `inline` attribute instead of referenced.
+    /// By default same as [`PartialComponent::partial_component`]. Derived implementation
+    /// returns [`Component::component`] and containers the inlined component of their items.
+    fn inline_partial_component() -> openapi::schema::Component {
+        Self::partial_component()
+    }
+}
+
+/// Trait for structs having separate write and read models derived with
+/// `#[component(split_read_write)]` attribute. The models are [`Create<T>`][create] without the
+/// read only fields and [`Read<T>`][read] without the write only fields of the component.
+///
+/// This trait is implemented by derive and there is no need to implement it manually.
+///
+/// [create]: struct.Create.html
+/// [read]: struct.Read.html
+pub trait SplitReadWrite: Component {
+    /// Names of the read only fields omitted from [`Create<T>`][create].
+    ///
+    /// [create]: struct.Create.html
+    fn read_only_fields() -> &'static [&'static str];
+
+    /// Names of the write only fields omitted from [`Read<T>`][read].
+    ///
+    /// [read]: struct.Read.html
+    fn write_only_fields() -> &'static [&'static str];
 }
 
+/// Write model of component `T` without its read only fields. Used e.g. as request body of
+/// create operations `request_body = utoipa::Create<Pet>`. The model is named after the component
+/// of `T` suffixed with `Create` e.g. `PetCreate`. See [`SplitReadWrite`].
+pub struct Create<T>(PhantomData<T>);
+
+/// Read model of component `T` without its write only fields. Used e.g. as response body
+/// `body = utoipa::Read<Pet>`. The model is named after the component of `T` suffixed with `Read`
+/// e.g. `PetRead`. See [`SplitReadWrite`].
+pub struct Read<T>(PhantomData<T>);
+
+macro_rules! impl_split_read_write_model {
+    ( $model:ident, $suffix:literal, $excluded_fields:ident ) => {
+        impl<T: SplitReadWrite> $model<T> {
+            fn model_name() -> Cow<'static, str> {
+                let name = T::component_name()
+                    .unwrap_or_else(|| partial_component::type_component_name::<T>().into());
+                Cow::Owned(format!("{}{}", name, $suffix))
+            }
+        }
+
+        impl<T: SplitReadWrite> Component for $model<T> {
+            fn component() -> openapi::schema::Component {
+                T::component().without_properties(T::$excluded_fields())
+            }
+
+            fn component_name() -> Option<Cow<'static, str>> {
+                Some(Self::model_name())
+            }
+        }
+
+        impl<T: SplitReadWrite> PartialComponent for $model<T> {
+            fn partial_component() -> openapi::schema::Component {
+                partial_component::component_ref::<Self>()
+            }
+
+            fn inline_partial_component() -> openapi::schema::Component {
+                Self::component()
+            }
+        }
+    };
+}
+
+impl_split_read_write_model!(Create, "Create", read_only_fields);
+impl_split_read_write_model!(Read, "Read", write_only_fields);
+
 /// Trait for implementing OpenAPI PathItem object with path.
 ///
 /// This trait is implemented via [`#[utoipa::path(...)]`][derive] attribute macro and there
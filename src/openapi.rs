@@ -11,8 +11,8 @@ pub use self::{
     response::{Response, ResponseBuilder, Responses, ResponsesBuilder},
     schema::{
         Array, ArrayBuilder, Component, ComponentFormat, ComponentType, Components,
-        ComponentsBuilder, Object, ObjectBuilder, OneOf, OneOfBuilder, Property, PropertyBuilder,
-        Ref, ToArray,
+        ComponentsBuilder, FreeForm, FreeFormBuilder, Object, ObjectBuilder, OneOf, OneOfBuilder,
+        Property, PropertyBuilder, Ref, ToArray,
     },
     security::SecurityRequirement,
     server::{Server, ServerBuilder, ServerVariable, ServerVariableBuilder},
@@ -20,16 +20,22 @@ pub use self::{
 };
 
 pub mod content;
+#[cfg(feature = "serde_json")]
+pub mod example;
 pub mod external_docs;
 pub mod header;
 pub mod info;
 pub mod path;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 pub mod request_body;
 pub mod response;
 pub mod schema;
 pub mod security;
 pub mod server;
 pub mod tag;
+#[cfg(feature = "validation")]
+pub mod validation;
 pub mod xml;
 
 builder! {
@@ -134,6 +140,81 @@ impl OpenApi {
         }
     }
 
+    /// Fill missing examples of request bodies, responses and parameters with examples generated
+    /// from their schemas. References are resolved from [`Components`] of the [`OpenApi`]. Examples
+    /// are not filled for schemas whose example cannot be generated e.g. because of a required
+    /// property referencing an unknown component. See [`generate`][generate] for more details how
+    /// the examples are generated.
+    ///
+    /// Same can be done with [`FillExamples`][fill_examples] modifier in `#[openapi(modifiers(&FillExamples))]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, Paths};
+    /// let mut openapi = OpenApi::new(Info::new("pet api", "0.1.0"), Paths::new());
+    /// openapi.fill_examples();
+    /// ```
+    ///
+    /// [generate]: example/fn.generate.html
+    /// [fill_examples]: example/struct.FillExamples.html
+    #[cfg(feature = "serde_json")]
+    pub fn fill_examples(&mut self) {
+        let OpenApi {
+            paths, components, ..
+        } = self;
+        let empty_components = Components::new();
+        let components = components.as_ref().unwrap_or(&empty_components);
+
+        let generate_example = |schema: &Component| {
+            Some(example::generate(schema, components)).filter(|example| !example.is_null())
+        };
+        let fill_content_example = |content: &mut Content| {
+            if content.example.is_none() {
+                content.example = generate_example(&content.schema);
+            }
+        };
+        let fill_parameter_example = |parameter: &mut path::Parameter| {
+            if parameter.example.is_none() {
+                parameter.example = parameter.schema.as_ref().and_then(generate_example);
+            }
+            parameter
+                .content
+                .iter_mut()
+                .flat_map(|content| content.values_mut())
+                .for_each(fill_content_example);
+        };
+
+        paths.paths.values_mut().for_each(|path_item| {
+            path_item
+                .parameters
+                .iter_mut()
+                .flatten()
+                .for_each(fill_parameter_example);
+
+            path_item.operations.values_mut().for_each(|operation| {
+                operation
+                    .parameters
+                    .iter_mut()
+                    .flatten()
+                    .for_each(fill_parameter_example);
+
+                operation
+                    .request_body
+                    .iter_mut()
+                    .flat_map(|request_body| request_body.content.values_mut())
+                    .for_each(fill_content_example);
+
+                operation
+                    .responses
+                    .responses
+                    .values_mut()
+                    .flat_map(|response| response.content.values_mut())
+                    .for_each(fill_content_example);
+            });
+        });
+    }
+
     /// Converts this [`OpenApi`] to JSON String. This method essentially calls [`serde_json::to_string`] method. [^json]
     ///
     /// [^json]: **serde_json** feature is needed.
@@ -200,6 +281,9 @@ pub enum OpenApiVersion {
     /// Will serialize to `3.0.3` the latest from 3.0 serie.
     #[serde(rename = "3.0.3")]
     Version3,
+    /// Will serialize to `3.1.0` the latest from 3.1 serie.
+    #[serde(rename = "3.1.0")]
+    Version31,
 }
 
 impl Default for OpenApiVersion {
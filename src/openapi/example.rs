@@ -0,0 +1,375 @@
+//! Implements generation of example values from [`Component`]s.
+//!
+//! Generated examples can be used to fill missing examples of request bodies and responses thus
+//! UIs like Swagger UI are able to render representative payloads also for deeply nested components.
+use serde_json::{Map, Value};
+
+use crate::Modify;
+
+use super::{
+    schema::{Array, Component, ComponentFormat, ComponentType, Components, Property},
+    OpenApi,
+};
+
+/// Generate representative example value of `component` resolving references from `components`.
+///
+/// Value is generated with following rules.
+/// * Explicitly defined `example` is used if present. Otherwise `default` of the property is used
+///   and then first value of `enum_values`.
+/// * Otherwise value is derived from [`ComponentType`] and [`ComponentFormat`] e.g. `date` format
+///   generates a date string. Numbers respect `minimum` and `maximum`.
+/// * Arrays contain one item or `min_items` amount of items and [`OneOf`][one_of] uses its first item
+///   which can be generated.
+/// * Recursive references and references to unknown components cannot be generated. Optional
+///   properties of such values are omitted and arrays are shortened down to `min_items` to keep
+///   the example finite. Otherwise the enclosing component cannot be generated either and `null`
+///   is returned as the example.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use utoipa::openapi::{Components, ComponentFormat, ComponentType, ObjectBuilder, PropertyBuilder};
+/// # use utoipa::openapi::example;
+/// let pet = ObjectBuilder::new()
+///     .property("id", PropertyBuilder::new().component_type(ComponentType::Integer))
+///     .property(
+///         "born",
+///         PropertyBuilder::new()
+///             .component_type(ComponentType::String)
+///             .format(Some(ComponentFormat::Date)),
+///     )
+///     .into();
+///
+/// assert_eq!(
+///     example::generate(&pet, &Components::new()),
+///     json!({ "id": 0, "born": "2022-01-01" })
+/// );
+/// ```
+///
+/// [one_of]: ../schema/struct.OneOf.html
+pub fn generate(component: &Component, components: &Components) -> Value {
+    Generator {
+        components,
+        refs: Vec::new(),
+    }
+    .generate(component)
+    .unwrap_or(Value::Null)
+}
+
+/// [`Modify`] implementation filling missing examples of request bodies, responses and
+/// parameters with generated examples. See [`OpenApi::fill_examples`] for more details.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::OpenApi;
+/// # use utoipa::openapi::example::FillExamples;
+/// #[derive(OpenApi)]
+/// #[openapi(modifiers(&FillExamples))]
+/// struct ApiDoc;
+/// ```
+pub struct FillExamples;
+
+impl Modify for FillExamples {
+    fn modify(&self, openapi: &mut OpenApi) {
+        openapi.fill_examples();
+    }
+}
+
+struct Generator<'c> {
+    components: &'c Components,
+    refs: Vec<&'c str>,
+}
+
+impl<'c> Generator<'c> {
+    /// Generate example of the component. Returns `None` if the example cannot be generated
+    /// because of recursive or unknown references.
+    fn generate(&mut self, component: &'c Component) -> Option<Value> {
+        match component {
+            Component::Property(property) => Some(property_example(property)),
+            Component::FreeForm(free_form) => Some(
+                free_form
+                    .example
+                    .as_ref()
+                    .or(free_form.default.as_ref())
+                    .cloned()
+                    .unwrap_or_else(|| Value::Object(Map::new())),
+            ),
+            Component::Object(object) => {
+                if let Some(ref example) = object.example {
+                    return Some(example.clone());
+                }
+
+                let mut properties = object.properties.iter().collect::<Vec<_>>();
+                properties.sort_by_key(|(name, _)| *name);
+
+                let mut values = Map::new();
+                for (name, property) in properties {
+                    match self.generate(property) {
+                        Some(value) => {
+                            values.insert(name.clone(), value);
+                        }
+                        None if !object.required.contains(name) => (),
+                        None => return None,
+                    }
+                }
+
+                Some(Value::Object(values))
+            }
+            Component::Array(array) => self.array_example(array),
+            Component::OneOf(one_of) if one_of.items.is_empty() => Some(Value::Null),
+            Component::OneOf(one_of) => one_of.items.iter().find_map(|item| self.generate(item)),
+            Component::Ref(reference) => {
+                let name = reference
+                    .ref_location
+                    .strip_prefix("#/components/schemas/")
+                    .unwrap_or(&reference.ref_location);
+                if self.refs.contains(&name) {
+                    return None;
+                }
+
+                let component = self.components.schemas.get(name)?;
+                self.refs.push(name);
+                let value = self.generate(component);
+                self.refs.pop();
+
+                value
+            }
+        }
+    }
+
+    fn array_example(&mut self, array: &'c Array) -> Option<Value> {
+        if let Some(example) = array.example.as_ref().or(array.default.as_ref()) {
+            return Some(example.clone());
+        }
+
+        let len = array
+            .min_items
+            .unwrap_or(1)
+            .max(array.prefix_items.len())
+            .min(array.max_items.unwrap_or(usize::MAX));
+
+        let items = (0..len)
+            .map_while(|index| self.generate(array.prefix_items.get(index).unwrap_or(&array.items)))
+            .collect::<Vec<_>>();
+
+        if items.len() < array.min_items.unwrap_or(0) {
+            return None;
+        }
+
+        Some(Value::Array(items))
+    }
+}
+
+fn property_example(property: &Property) -> Value {
+    if let Some(example) = property
+        .example
+        .as_ref()
+        .or(property.default.as_ref())
+        .or_else(|| property.enum_values.iter().flatten().next())
+    {
+        return example.clone();
+    }
+
+    match property.component_type {
+        ComponentType::String => Value::String(
+            match &property.format {
+                Some(ComponentFormat::Date) => "2022-01-01",
+                Some(ComponentFormat::DateTime) => "2022-01-01T00:00:00Z",
+                Some(ComponentFormat::Time) => "00:00:00",
+                Some(ComponentFormat::Duration) => "PT0S",
+                Some(ComponentFormat::Uuid) => "00000000-0000-0000-0000-000000000000",
+                Some(ComponentFormat::Uri) => "https://example.com",
+                Some(ComponentFormat::Byte) => "ZXhhbXBsZQ==",
+                Some(ComponentFormat::Binary) => "",
+                Some(ComponentFormat::Password) => "password",
+                Some(ComponentFormat::Custom(format)) if format == "email" => "user@example.com",
+                _ => "string",
+            }
+            .to_string(),
+        ),
+        ComponentType::Integer => {
+            let number = clamp(0.0, property).ceil();
+            if number.is_sign_negative() {
+                Value::from(number as i64)
+            } else {
+                Value::from(number as u64)
+            }
+        }
+        ComponentType::Number => Value::from(clamp(0.0, property)),
+        ComponentType::Boolean => Value::Bool(true),
+        ComponentType::Array => Value::Array(Vec::new()),
+        ComponentType::Object => Value::Object(Map::new()),
+    }
+}
+
+/// Bring the number within the `minimum` and `maximum` of the property.
+fn clamp(number: f64, property: &Property) -> f64 {
+    let number = property
+        .minimum
+        .map_or(number, |minimum| number.max(minimum));
+
+    property
+        .maximum
+        .map_or(number, |maximum| number.min(maximum))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::{
+        schema::{
+            ArrayBuilder, ComponentsBuilder, ObjectBuilder, OneOfBuilder, PropertyBuilder, Ref,
+        },
+        ToArray,
+    };
+
+    use super::*;
+
+    #[test]
+    fn generate_property_examples() {
+        let examples = [
+            (
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .example(Some(json!("doggo")))
+                    .default(Some(json!("cat"))),
+                json!("doggo"),
+            ),
+            (
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .default(Some(json!("cat")))
+                    .enum_values(Some(["dog", "cat"])),
+                json!("cat"),
+            ),
+            (
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .enum_values(Some(["dog", "cat"])),
+                json!("dog"),
+            ),
+            (
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .format(Some(ComponentFormat::DateTime)),
+                json!("2022-01-01T00:00:00Z"),
+            ),
+            (
+                PropertyBuilder::new()
+                    .component_type(ComponentType::Integer)
+                    .minimum(Some(1.0)),
+                json!(1),
+            ),
+            (
+                PropertyBuilder::new()
+                    .component_type(ComponentType::Number)
+                    .maximum(Some(-0.5)),
+                json!(-0.5),
+            ),
+            (
+                PropertyBuilder::new().component_type(ComponentType::Boolean),
+                json!(true),
+            ),
+        ];
+
+        for (property, expected) in examples {
+            assert_eq!(generate(&property.into(), &Components::new()), expected);
+        }
+    }
+
+    #[test]
+    fn generate_nested_example_with_refs() {
+        let components = ComponentsBuilder::new()
+            .component(
+                "Owner",
+                ObjectBuilder::new()
+                    .property("name", Property::new(ComponentType::String))
+                    .property("pets", Ref::from_component_name("Pet").to_array()),
+            )
+            .component(
+                "Pet",
+                ObjectBuilder::new()
+                    .property("owner", Ref::from_component_name("Owner"))
+                    .property(
+                        "tags",
+                        ArrayBuilder::new()
+                            .items(Property::new(ComponentType::String))
+                            .min_items(Some(2)),
+                    )
+                    .property(
+                        "status",
+                        OneOfBuilder::new()
+                            .item(Property::new(ComponentType::Integer))
+                            .item(Property::new(ComponentType::String)),
+                    ),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&Ref::from_component_name("Pet").into(), &components),
+            json!({
+                "owner": { "name": "string", "pets": [] },
+                "status": 0,
+                "tags": ["string", "string"]
+            })
+        );
+    }
+
+    #[test]
+    fn generate_example_without_unresolvable_refs() {
+        let components = ComponentsBuilder::new()
+            .component(
+                "Pet",
+                ObjectBuilder::new()
+                    .property("name", Property::new(ComponentType::String))
+                    .required("name")
+                    .property("owner", Ref::from_component_name("Owner"))
+                    .property(
+                        "friends",
+                        ArrayBuilder::new()
+                            .items(Ref::from_component_name("Pet"))
+                            .min_items(Some(1)),
+                    ),
+            )
+            .component(
+                "Owner",
+                ObjectBuilder::new()
+                    .property("pet", Ref::from_component_name("Pet"))
+                    .required("pet"),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&Ref::from_component_name("Pet").into(), &components),
+            json!({ "name": "string" })
+        );
+
+        let unknown: Component = ObjectBuilder::new()
+            .property("category", Ref::from_component_name("Category"))
+            .required("category")
+            .into();
+        assert_eq!(generate(&unknown, &components), Value::Null);
+
+        let one_of: Component = OneOfBuilder::new()
+            .item(Ref::from_component_name("Category"))
+            .item(Property::new(ComponentType::Boolean))
+            .into();
+        assert_eq!(generate(&one_of, &components), json!(true));
+    }
+
+    #[test]
+    fn generate_object_with_explicit_example() {
+        let component: Component = ObjectBuilder::new()
+            .property("name", Property::new(ComponentType::String))
+            .example(Some(json!({ "name": "doggo" })))
+            .into();
+
+        assert_eq!(
+            generate(&component, &Components::new()),
+            json!({ "name": "doggo" })
+        );
+    }
+}
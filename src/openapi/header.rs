@@ -3,26 +3,55 @@
 //! [header]: https://spec.openapis.org/oas/latest.html#header-object
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
 
-use super::{build_fn, builder, from, new, set_value, Component, ComponentType, Property};
+use super::{
+    build_fn, from, new, path::ParameterStyle, set_value, Component, ComponentType, Deprecated,
+    Property, Required,
+};
 
-builder! {
-    HeaderBuilder;
+/// Implements [OpenAPI Header Object][header] for response headers.
+///
+/// [header]: https://spec.openapis.org/oas/latest.html#header-object
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Header {
+    /// Schema of header type.
+    pub schema: Component,
 
-    /// Implements [OpenAPI Header Object][header] for response headers.
-    ///
-    /// [header]: https://spec.openapis.org/oas/latest.html#header-object
-    #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Clone)]
-    #[cfg_attr(feature = "debug", derive(Debug))]
-    pub struct Header {
-        /// Schema of header type.
-        pub schema: Component,
-
-        /// Additional descripiton of the header value.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub description: Option<String>,
-    }
+    /// Additional descripiton of the header value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Declares whether the header is always sent with the response or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<Required>,
+
+    /// Declares the header deprecated status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecated>,
+
+    /// Describes how header value is serialized. Only [`ParameterStyle::Simple`] is
+    /// allowed for headers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ParameterStyle>,
+
+    /// When _`true`_ _`object`_ typed header values are serialized as comma separated
+    /// _`key=value`_ pairs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explode: Option<bool>,
+
+    /// Example of the header value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub example: Option<Value>,
+
+    /// Example of the header value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub example: Option<String>,
 }
 
 impl Header {
@@ -57,11 +86,43 @@ impl Default for Header {
         Self {
             description: Default::default(),
             schema: Property::new(ComponentType::String).into(),
+            required: Default::default(),
+            deprecated: Default::default(),
+            style: Default::default(),
+            explode: Default::default(),
+            example: Default::default(),
         }
     }
 }
 
+/// Builder for [`Header`] with chainable configuration methods to create a new [`Header`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct HeaderBuilder {
+    schema: Component,
+
+    description: Option<String>,
+
+    required: Option<Required>,
+
+    deprecated: Option<Deprecated>,
+
+    style: Option<ParameterStyle>,
+
+    explode: Option<bool>,
+
+    #[cfg(feature = "serde_json")]
+    example: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    example: Option<String>,
+}
+
+from!(Header HeaderBuilder schema, description, required, deprecated, style, explode, example);
+
 impl HeaderBuilder {
+    new!(pub HeaderBuilder);
+
     /// Add schema of header.
     pub fn schema<I: Into<Component>>(mut self, component: I) -> Self {
         set_value!(self schema component.into())
@@ -71,4 +132,38 @@ impl HeaderBuilder {
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
+
+    /// Add or change whether the header is required in the response.
+    pub fn required(mut self, required: Option<Required>) -> Self {
+        set_value!(self required required)
+    }
+
+    /// Add or change header deprecated declaration.
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change serialization style of the header value.
+    pub fn style(mut self, style: Option<ParameterStyle>) -> Self {
+        set_value!(self style style)
+    }
+
+    /// Define whether _`object`_ typed header value is exploded or not.
+    pub fn explode(mut self, explode: Option<bool>) -> Self {
+        set_value!(self explode explode)
+    }
+
+    /// Add or change example of the header value.
+    #[cfg(feature = "serde_json")]
+    pub fn example(mut self, example: Option<Value>) -> Self {
+        set_value!(self example example)
+    }
+
+    /// Add or change example of the header value.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn example<S: Into<String>>(mut self, example: Option<S>) -> Self {
+        set_value!(self example example.map(|example| example.into()))
+    }
+
+    build_fn!(pub Header schema, description, required, deprecated, style, explode, example);
 }
@@ -0,0 +1,491 @@
+//! Implements [proptest][proptest] strategies generating JSON values from [`Component`]s.
+//!
+//! Strategies can be used to generate valid request bodies straight from the documented
+//! components e.g. to fuzz the handlers in property based tests.
+//!
+//! Strategies are available with **proptest** feature.
+//!
+//! [proptest]: https://crates.io/crates/proptest
+use std::{error::Error, fmt::Display};
+
+use proptest::{
+    collection, option,
+    prelude::{any, BoxedStrategy, Just, Strategy},
+    sample,
+    strategy::Union,
+};
+use serde_json::{Map, Value};
+
+use super::schema::{Array, Component, ComponentFormat, ComponentType, Components, Property};
+
+/// Default maximum length of generated arrays without [`Array::max_items`].
+const DEFAULT_MAX_ITEMS: usize = 8;
+
+/// Create a [`Strategy`] generating values of `component` resolving references from `components`.
+///
+/// Strategy honours following rules of the component.
+/// * [`ComponentType`] and [`ComponentFormat`] of properties e.g. `date` format generates date
+///   strings and `int32` format integers within range of `i32`.
+/// * `enum_values` of properties, as well as `minimum` and `maximum` of numbers.
+/// * Required properties of objects are always generated while optional properties may be omitted.
+/// * `min_items`, `max_items` and `unique_items` of arrays. Arrays have at most
+///   8 items unless `max_items` is defined.
+///   Items are generated positionally only for `prefix_items` available with **openapi_31** feature.
+/// * [`OneOf`][one_of] generates value of any of its items.
+///
+/// Recursive references are omitted from optional properties and arrays down to `min_items` to
+/// keep the values finite.
+///
+/// # Errors
+///
+/// Returns [`StrategyError`] if a reference to a component not found from `components` is found or
+/// if a finite value cannot be generated e.g. because of a required property referencing its own
+/// component.
+///
+/// # Examples
+///
+/// ```rust
+/// # use proptest::test_runner::TestRunner;
+/// # use utoipa::openapi::{Components, ComponentType, ObjectBuilder, Property};
+/// use utoipa::openapi::proptest::strategy;
+/// let pet = ObjectBuilder::new()
+///     .property("name", Property::new(ComponentType::String))
+///     .required("name")
+///     .into();
+///
+/// let mut runner = TestRunner::default();
+/// runner
+///     .run(&strategy(&pet, &Components::new()).unwrap(), |pet| {
+///         assert!(pet["name"].is_string());
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+///
+/// [one_of]: ../schema/struct.OneOf.html
+pub fn strategy(
+    component: &Component,
+    components: &Components,
+) -> Result<BoxedStrategy<Value>, StrategyError> {
+    StrategyBuilder {
+        components,
+        refs: Vec::new(),
+    }
+    .build(component)?
+    .ok_or(StrategyError::InfiniteRecursion)
+}
+
+/// Error returned by [`strategy`] when the component cannot be turned into a strategy.
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Debug)]
+pub enum StrategyError {
+    /// Referenced component was not found from [`Components`].
+    UnresolvedRef(String),
+    /// Finite value cannot be generated because the component requires a value of itself.
+    InfiniteRecursion,
+}
+
+impl Display for StrategyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnresolvedRef(reference) => write!(f, "unresolved reference `{}`", reference),
+            Self::InfiniteRecursion => {
+                write!(f, "cannot generate finite value of recursive component")
+            }
+        }
+    }
+}
+
+impl Error for StrategyError {}
+
+struct StrategyBuilder<'c> {
+    components: &'c Components,
+    refs: Vec<&'c str>,
+}
+
+impl<'c> StrategyBuilder<'c> {
+    /// Build strategy of the component. Returns `Ok(None)` if a finite value cannot be generated
+    /// because of recursive references.
+    fn build(
+        &mut self,
+        component: &'c Component,
+    ) -> Result<Option<BoxedStrategy<Value>>, StrategyError> {
+        match component {
+            Component::Property(property) => Ok(Some(property_strategy(property))),
+            Component::FreeForm(_) => Ok(Some(free_form_strategy())),
+            Component::Object(object) => {
+                let mut properties = object.properties.iter().collect::<Vec<_>>();
+                properties.sort_by_key(|(name, _)| *name);
+
+                let mut strategies = Vec::with_capacity(properties.len());
+                for (name, property) in properties {
+                    let required = object.required.contains(name);
+                    let strategy = match (self.build(property)?, required) {
+                        (Some(strategy), true) => strategy.prop_map(Some).boxed(),
+                        (Some(strategy), false) => option::of(strategy).boxed(),
+                        (None, true) => return Ok(None),
+                        (None, false) => Just(None).boxed(),
+                    };
+                    let name = name.clone();
+
+                    strategies.push(
+                        strategy.prop_map(move |value| value.map(|value| (name.clone(), value))),
+                    );
+                }
+
+                Ok(Some(
+                    strategies
+                        .prop_map(|properties| {
+                            Value::Object(properties.into_iter().flatten().collect::<Map<_, _>>())
+                        })
+                        .boxed(),
+                ))
+            }
+            Component::Array(array) => self.array_strategy(array),
+            Component::OneOf(one_of) => {
+                let mut items = Vec::with_capacity(one_of.items.len());
+                for item in &one_of.items {
+                    items.extend(self.build(item)?);
+                }
+
+                if items.is_empty() {
+                    Ok(None)
+                } else {
+                    Ok(Some(Union::new(items).boxed()))
+                }
+            }
+            Component::Ref(reference) => {
+                let name = reference
+                    .ref_location
+                    .strip_prefix("#/components/schemas/")
+                    .unwrap_or(&reference.ref_location);
+                if self.refs.contains(&name) {
+                    return Ok(None);
+                }
+
+                let component =
+                    self.components.schemas.get(name).ok_or_else(|| {
+                        StrategyError::UnresolvedRef(reference.ref_location.clone())
+                    })?;
+                self.refs.push(name);
+                let strategy = self.build(component);
+                self.refs.pop();
+
+                strategy
+            }
+        }
+    }
+
+    fn array_strategy(
+        &mut self,
+        array: &'c Array,
+    ) -> Result<Option<BoxedStrategy<Value>>, StrategyError> {
+        let min_items = array.min_items.unwrap_or_default();
+        let max_items = array
+            .max_items
+            .unwrap_or_else(|| min_items.max(array.prefix_items.len()) + DEFAULT_MAX_ITEMS);
+
+        let mut prefix_items = Vec::new();
+        for item in array.prefix_items.iter().take(max_items) {
+            match self.build(item)? {
+                Some(item) => prefix_items.push(item),
+                None => break,
+            }
+        }
+        let len = prefix_items.len();
+        if len < array.prefix_items.len().min(max_items) {
+            // positional items which cannot be generated end the array
+            return Ok((min_items <= len).then(|| prefix_items.prop_map(Value::Array).boxed()));
+        }
+        let (min_items, max_items) = (min_items.saturating_sub(len), max_items - len);
+
+        let items = match self.build(&array.items)? {
+            Some(items) => collection::vec(items, min_items..=max_items).boxed(),
+            None if min_items == 0 => Just(Vec::new()).boxed(),
+            None => return Ok(None),
+        };
+
+        let strategy = (prefix_items, items).prop_map(|(mut prefix_items, items)| {
+            prefix_items.extend(items);
+            prefix_items
+        });
+
+        if array.unique_items == Some(true) {
+            Ok(Some(
+                strategy
+                    .prop_filter("array items must be unique", |items| {
+                        items
+                            .iter()
+                            .enumerate()
+                            .all(|(index, item)| !items[..index].contains(item))
+                    })
+                    .prop_map(Value::Array)
+                    .boxed(),
+            ))
+        } else {
+            Ok(Some(strategy.prop_map(Value::Array).boxed()))
+        }
+    }
+}
+
+fn property_strategy(property: &Property) -> BoxedStrategy<Value> {
+    if let Some(enum_values) = property
+        .enum_values
+        .as_ref()
+        .filter(|values| !values.is_empty())
+    {
+        return sample::select(enum_values.clone()).boxed();
+    }
+
+    match property.component_type {
+        ComponentType::String => string_strategy(property.format.as_ref()),
+        ComponentType::Integer => {
+            let (type_min, type_max) = match property.format {
+                Some(ComponentFormat::Int32) => (i32::MIN as f64, i32::MAX as f64),
+                _ => (i64::MIN as f64, i64::MAX as f64),
+            };
+            let minimum = property.minimum.map_or(type_min, f64::ceil).max(type_min) as i64;
+            let maximum = property.maximum.map_or(type_max, f64::floor).min(type_max) as i64;
+
+            (minimum..=maximum).prop_map(Value::from).boxed()
+        }
+        ComponentType::Number => match (property.minimum, property.maximum) {
+            (None, None) => {
+                use proptest::num::f64::{NORMAL, SUBNORMAL, ZERO};
+                (NORMAL | SUBNORMAL | ZERO).prop_map(Value::from).boxed()
+            }
+            (minimum, maximum) => (minimum.unwrap_or(f64::MIN / 2.0)
+                ..=maximum.unwrap_or(f64::MAX / 2.0))
+                .prop_map(Value::from)
+                .boxed(),
+        },
+        ComponentType::Boolean => any::<bool>().prop_map(Value::Bool).boxed(),
+        ComponentType::Array => Just(Value::Array(Vec::new())).boxed(),
+        ComponentType::Object => Just(Value::Object(Map::new())).boxed(),
+    }
+}
+
+fn free_form_strategy() -> BoxedStrategy<Value> {
+    Union::new([
+        Just(Value::Null).boxed(),
+        any::<bool>().prop_map(Value::Bool).boxed(),
+        any::<i64>().prop_map(Value::from).boxed(),
+        any::<String>().prop_map(Value::String).boxed(),
+    ])
+    .boxed()
+}
+
+fn string_strategy(format: Option<&ComponentFormat>) -> BoxedStrategy<Value> {
+    let date = || {
+        (1970..=9999u32, 1..=12u32, 1..=28u32)
+            .prop_map(|(year, month, day)| format!("{:04}-{:02}-{:02}", year, month, day))
+    };
+    let time = || {
+        (0..=23u32, 0..=59u32, 0..=59u32)
+            .prop_map(|(hour, minute, second)| format!("{:02}:{:02}:{:02}", hour, minute, second))
+    };
+
+    let strategy = match format {
+        Some(ComponentFormat::Date) => date().boxed(),
+        Some(ComponentFormat::DateTime) => (date(), time())
+            .prop_map(|(date, time)| format!("{}T{}Z", date, time))
+            .boxed(),
+        Some(ComponentFormat::Time) => time().boxed(),
+        Some(ComponentFormat::Duration) => "PT[0-9]{1,5}S".boxed(),
+        Some(ComponentFormat::Uuid) => any::<u128>()
+            .prop_map(|uuid| {
+                let uuid = format!("{:032x}", uuid);
+                format!(
+                    "{}-{}-{}-{}-{}",
+                    &uuid[..8],
+                    &uuid[8..12],
+                    &uuid[12..16],
+                    &uuid[16..20],
+                    &uuid[20..]
+                )
+            })
+            .boxed(),
+        Some(ComponentFormat::Uri) => "https://[a-z]{1,10}\\.com/[a-z0-9]{0,10}".boxed(),
+        Some(ComponentFormat::Byte) => "([A-Za-z0-9+/]{4}){0,8}".boxed(),
+        Some(ComponentFormat::Custom(format)) if format == "email" => {
+            "[a-z0-9]{1,10}@[a-z]{1,10}\\.com".boxed()
+        }
+        _ => any::<String>().boxed(),
+    };
+
+    strategy.prop_map(Value::String).boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::test_runner::TestRunner;
+    use serde_json::json;
+
+    use crate::openapi::{
+        schema::{
+            ArrayBuilder, ComponentsBuilder, ObjectBuilder, OneOfBuilder, PropertyBuilder, Ref,
+        },
+        ToArray,
+    };
+
+    use super::*;
+
+    fn run<F: Fn(&Value)>(component: &Component, components: &Components, test: F) {
+        TestRunner::default()
+            .run(&strategy(component, components).unwrap(), |value| {
+                test(&value);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn strategy_generates_properties() {
+        let component: Component = ObjectBuilder::new()
+            .property(
+                "id",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::Integer)
+                    .format(Some(ComponentFormat::Int32))
+                    .minimum(Some(1.0)),
+            )
+            .required("id")
+            .property(
+                "born",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .format(Some(ComponentFormat::Date)),
+            )
+            .required("born")
+            .property(
+                "status",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .enum_values(Some(["Available", "Sold"])),
+            )
+            .required("status")
+            .property(
+                "weight",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::Number)
+                    .minimum(Some(0.5))
+                    .maximum(Some(100.0)),
+            )
+            .required("weight")
+            .into();
+
+        run(&component, &Components::new(), |pet| {
+            let id = pet["id"].as_i64().unwrap();
+            assert!((1..=i64::from(i32::MAX)).contains(&id), "id: {}", id);
+
+            let born = pet["born"].as_str().unwrap();
+            assert_eq!(born.len(), 10, "born: {}", born);
+
+            assert!(
+                pet["status"] == json!("Available") || pet["status"] == json!("Sold"),
+                "status: {}",
+                pet["status"]
+            );
+
+            let weight = pet["weight"].as_f64().unwrap();
+            assert!((0.5..=100.0).contains(&weight), "weight: {}", weight);
+        });
+    }
+
+    #[test]
+    fn strategy_generates_optional_properties() {
+        let component: Component = ObjectBuilder::new()
+            .property("name", Property::new(ComponentType::String))
+            .required("name")
+            .property("nickname", Property::new(ComponentType::String))
+            .into();
+
+        run(&component, &Components::new(), |pet| {
+            let pet = pet.as_object().unwrap();
+            assert!(pet["name"].is_string());
+            assert!(pet.get("nickname").into_iter().all(Value::is_string));
+        });
+    }
+
+    #[test]
+    fn strategy_generates_arrays() {
+        let component: Component = ArrayBuilder::new()
+            .items(Property::new(ComponentType::Boolean))
+            .min_items(Some(1))
+            .max_items(Some(2))
+            .unique_items(Some(true))
+            .into();
+
+        run(&component, &Components::new(), |items| {
+            let items = items.as_array().unwrap();
+            assert!((1..=2).contains(&items.len()), "items: {:?}", items);
+            assert!(
+                items.len() == 1 || items[0] != items[1],
+                "items: {:?}",
+                items
+            );
+        });
+    }
+
+    #[test]
+    fn strategy_generates_one_of_and_recursive_refs() {
+        let components = ComponentsBuilder::new()
+            .component(
+                "Node",
+                ObjectBuilder::new()
+                    .property(
+                        "value",
+                        OneOfBuilder::new()
+                            .item(Property::new(ComponentType::Integer))
+                            .item(Property::new(ComponentType::Boolean)),
+                    )
+                    .required("value")
+                    .property("children", Ref::from_component_name("Node").to_array())
+                    .required("children")
+                    .property("parent", Ref::from_component_name("Node")),
+            )
+            .build();
+
+        run(
+            &Ref::from_component_name("Node").into(),
+            &components,
+            |node| {
+                assert!(node["value"].is_i64() || node["value"].is_boolean());
+                assert_eq!(node["children"], json!([]));
+                assert!(node.get("parent").is_none());
+            },
+        );
+    }
+
+    #[test]
+    fn strategy_fails_on_unknown_component() {
+        let component: Component = ObjectBuilder::new()
+            .property("owner", Ref::from_component_name("Owner"))
+            .required("owner")
+            .into();
+
+        assert_eq!(
+            strategy(&component, &Components::new()).err(),
+            Some(StrategyError::UnresolvedRef(
+                "#/components/schemas/Owner".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn strategy_fails_on_required_recursive_ref() {
+        let components = ComponentsBuilder::new()
+            .component(
+                "Node",
+                ObjectBuilder::new()
+                    .property("parent", Ref::from_component_name("Node"))
+                    .required("parent"),
+            )
+            .build();
+
+        assert_eq!(
+            strategy(&Ref::from_component_name("Node").into(), &components).err(),
+            Some(StrategyError::InfiniteRecursion)
+        );
+    }
+}
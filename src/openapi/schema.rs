@@ -4,7 +4,7 @@
 //! [schema]: https://spec.openapis.org/oas/latest.html#schema-object
 use std::collections::HashMap;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 #[cfg(feature = "serde_json")]
 use serde_json::Value;
 
@@ -111,6 +111,27 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add iterator of [`Component`]s to [`Components`].
+    ///
+    /// Accepts iterator of tuples where first is the name of the component and second is the
+    /// component itself.
+    pub fn components_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        S: Into<String>,
+        C: Into<Component>,
+    >(
+        mut self,
+        components: I,
+    ) -> Self {
+        self.schemas.extend(
+            components
+                .into_iter()
+                .map(|(name, component)| (name.into(), component.into())),
+        );
+
+        self
+    }
+
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -159,6 +180,9 @@ pub enum Component {
     ///
     /// [discriminator]: https://spec.openapis.org/oas/latest.html#components-object
     OneOf(OneOf),
+    /// Defines free-form component `{}` without type which accepts any value. Used for dynamic
+    /// values such as `serde_json::Value`.
+    FreeForm(FreeForm),
 }
 
 impl Default for Component {
@@ -167,27 +191,140 @@ impl Default for Component {
     }
 }
 
-builder! {
-    OneOfBuilder;
-
-    /// OneOf [Discriminator Object][discriminator] component holds
-    /// multiple components together where API endpoint could return any of them.
+impl Component {
+    /// Create a copy of this [`Component`] without the given [`Object`] properties. The properties
+    /// are removed from the required properties as well. Used to derive the models of _write_
+    /// requests without the read only properties and of _read_ responses without the write only
+    /// properties.
     ///
-    /// See [`Component::OneOf`] for more details.
+    /// # Examples
     ///
-    /// [discriminator]: https://spec.openapis.org/oas/latest.html#components-object
-    #[derive(Serialize, Deserialize, Clone, Default)]
-    #[cfg_attr(feature = "debug", derive(Debug))]
-    pub struct OneOf {
-        /// Components of _OneOf_ component.
-        #[serde(rename = "oneOf")]
-        pub items: Vec<Component>,
-
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub description: Option<String>,
+    /// ```rust
+    /// # use utoipa::openapi::schema::{Component, ObjectBuilder, PropertyBuilder, ComponentType};
+    /// let pet: Component = ObjectBuilder::new()
+    ///     .property(
+    ///         "id",
+    ///         PropertyBuilder::new()
+    ///             .component_type(ComponentType::Integer)
+    ///             .read_only(Some(true)),
+    ///     )
+    ///     .required("id")
+    ///     .property("name", PropertyBuilder::new().component_type(ComponentType::String))
+    ///     .required("name")
+    ///     .into();
+    ///
+    /// let pet_create = pet.without_properties(&["id"]);
+    /// ```
+    pub fn without_properties(&self, names: &[&str]) -> Self {
+        match self {
+            Self::Object(object) => {
+                let mut object = object.clone();
+                object
+                    .properties
+                    .retain(|name, _| !names.contains(&name.as_str()));
+                object
+                    .required
+                    .retain(|name| !names.contains(&name.as_str()));
+
+                Self::Object(object)
+            }
+            component => component.clone(),
+        }
     }
+
+    to_array_builder!();
+}
+
+/// OneOf [Discriminator Object][discriminator] component holds
+/// multiple components together where API endpoint could return any of them.
+///
+/// See [`Component::OneOf`] for more details.
+///
+/// [discriminator]: https://spec.openapis.org/oas/latest.html#components-object
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OneOf {
+    /// Components of _OneOf_ component.
+    #[serde(rename = "oneOf")]
+    pub items: Vec<Component>,
+
+    /// Optional title of the [`OneOf`] component.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Changes the [`OneOf`] deprecated status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecated>,
+
+    /// Default value which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub default: Option<Value>,
+
+    /// Default value which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub default: Option<String>,
+
+    /// Example shown in UI of the value for richier documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub example: Option<Value>,
+
+    /// Example shown in UI of the value for richier documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub example: Option<String>,
+
+    /// Write only [`OneOf`] will be only sent in _write_ requests like _POST, PUT_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_only: Option<bool>,
+
+    /// Read only [`OneOf`] will be only sent in _read_ requests like _GET_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+
+    /// Additional [`Xml`] formatting of the [`OneOf`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xml: Option<Xml>,
 }
 
+/// Builder for [`OneOf`] with chainable configuration methods to create a new [`OneOf`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OneOfBuilder {
+    items: Vec<Component>,
+
+    title: Option<String>,
+
+    description: Option<String>,
+
+    deprecated: Option<Deprecated>,
+
+    #[cfg(feature = "serde_json")]
+    default: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    default: Option<String>,
+
+    #[cfg(feature = "serde_json")]
+    example: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    example: Option<String>,
+
+    write_only: Option<bool>,
+
+    read_only: Option<bool>,
+
+    xml: Option<Xml>,
+}
+
+from!(OneOf OneOfBuilder items, title, description, deprecated, default, example, write_only, read_only, xml);
+
 impl OneOf {
     /// Construct a new [`OneOf`] component.
     pub fn new() -> Self {
@@ -211,12 +348,14 @@ impl OneOf {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             items: Vec::with_capacity(capacity),
-            description: None
+            ..Default::default()
         }
     }
 }
 
 impl OneOfBuilder {
+    new!(pub OneOfBuilder);
+
     /// Adds a given [`Component`] to [`OneOf`] [Discriminator Object][discriminator]
     ///
     /// [discriminator]: https://spec.openapis.org/oas/latest.html#components-object
@@ -226,14 +365,64 @@ impl OneOfBuilder {
         self
     }
 
+    /// Add or change optional title for `OneOf` component.
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        set_value!(self title title.map(|title| title.into()))
+    }
+
     /// Add or change optional description for `OneOf` component.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
 
+    /// Add or change deprecated status for `OneOf` component.
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change default value which is provided when user has not provided the input.
+    #[cfg(feature = "serde_json")]
+    pub fn default(mut self, default: Option<Value>) -> Self {
+        set_value!(self default default)
+    }
+
+    /// Add or change default value which is provided when user has not provided the input.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn default<I: Into<String>>(mut self, default: Option<I>) -> Self {
+        set_value!(self default default.map(|default| default.into()))
+    }
+
+    /// Add or change example shown in UI of the value for richier documentation.
+    #[cfg(feature = "serde_json")]
+    pub fn example(mut self, example: Option<Value>) -> Self {
+        set_value!(self example example)
+    }
+
+    /// Add or change example shown in UI of the value for richier documentation.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn example<I: Into<String>>(mut self, example: Option<I>) -> Self {
+        set_value!(self example example.map(|example| example.into()))
+    }
+
+    /// Add or change write only flag for `OneOf` component.
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for `OneOf` component.
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
+    /// Add or change additional [`Xml`] formatting of the `OneOf` component.
+    pub fn xml(mut self, xml: Option<Xml>) -> Self {
+        set_value!(self xml xml)
+    }
+
     to_array_builder!();
-}
 
+    build_fn!(pub OneOf items, title, description, deprecated, default, example, write_only, read_only, xml);
+}
 
 impl From<OneOf> for Component {
     fn from(one_of: OneOf) -> Self {
@@ -243,6 +432,170 @@ impl From<OneOf> for Component {
 
 component_from_builder!(OneOfBuilder);
 
+/// Free-form component `{}` without type which accepts any value.
+///
+/// See [`Component::FreeForm`] for more details.
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct FreeForm {
+    /// Optional title of the [`FreeForm`] component.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Description of the [`FreeForm`] component. Markdown syntax is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Changes the [`FreeForm`] deprecated status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecated>,
+
+    /// Default value which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub default: Option<Value>,
+
+    /// Default value which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub default: Option<String>,
+
+    /// Example shown in UI of the value for richier documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub example: Option<Value>,
+
+    /// Example shown in UI of the value for richier documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub example: Option<String>,
+
+    /// Write only [`FreeForm`] will be only sent in _write_ requests like _POST, PUT_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_only: Option<bool>,
+
+    /// Read only [`FreeForm`] will be only sent in _read_ requests like _GET_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+
+    /// Additional [`Xml`] formatting of the [`FreeForm`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xml: Option<Xml>,
+}
+
+impl FreeForm {
+    /// Construct a new [`FreeForm`] component.
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+}
+
+impl ToArray for FreeForm {}
+
+/// Builder for [`FreeForm`] with chainable configuration methods to create a new [`FreeForm`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct FreeFormBuilder {
+    title: Option<String>,
+
+    description: Option<String>,
+
+    deprecated: Option<Deprecated>,
+
+    #[cfg(feature = "serde_json")]
+    default: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    default: Option<String>,
+
+    #[cfg(feature = "serde_json")]
+    example: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    example: Option<String>,
+
+    write_only: Option<bool>,
+
+    read_only: Option<bool>,
+
+    xml: Option<Xml>,
+}
+
+from!(FreeForm FreeFormBuilder title, description, deprecated, default, example, write_only, read_only, xml);
+
+impl FreeFormBuilder {
+    new!(pub FreeFormBuilder);
+
+    /// Add or change optional title for `FreeForm` component.
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        set_value!(self title title.map(|title| title.into()))
+    }
+
+    /// Add or change optional description for `FreeForm` component.
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add or change deprecated status for `FreeForm` component.
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change default value which is provided when user has not provided the input.
+    #[cfg(feature = "serde_json")]
+    pub fn default(mut self, default: Option<Value>) -> Self {
+        set_value!(self default default)
+    }
+
+    /// Add or change default value which is provided when user has not provided the input.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn default<I: Into<String>>(mut self, default: Option<I>) -> Self {
+        set_value!(self default default.map(|default| default.into()))
+    }
+
+    /// Add or change example shown in UI of the value for richier documentation.
+    #[cfg(feature = "serde_json")]
+    pub fn example(mut self, example: Option<Value>) -> Self {
+        set_value!(self example example)
+    }
+
+    /// Add or change example shown in UI of the value for richier documentation.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn example<I: Into<String>>(mut self, example: Option<I>) -> Self {
+        set_value!(self example example.map(|example| example.into()))
+    }
+
+    /// Add or change write only flag for `FreeForm` component.
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for `FreeForm` component.
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
+    /// Add or change additional [`Xml`] formatting of the `FreeForm` component.
+    pub fn xml(mut self, xml: Option<Xml>) -> Self {
+        set_value!(self xml xml)
+    }
+
+    to_array_builder!();
+
+    build_fn!(pub FreeForm title, description, deprecated, default, example, write_only, read_only, xml);
+}
+
+impl From<FreeForm> for Component {
+    fn from(free_form: FreeForm) -> Self {
+        Self::FreeForm(free_form)
+    }
+}
+
+component_from_builder!(FreeFormBuilder);
+
 /// Implements special subset of [OpenAPI Schema Object][schema] which can be
 /// used to define field property or enum values or type for array items.
 /// 
@@ -259,6 +612,10 @@ pub struct Property {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<ComponentFormat>,
 
+    /// Title of the property.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
     /// Description of the property. Markdown syntax is supported.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -275,8 +632,38 @@ pub struct Property {
 
     /// Enum type property possible variants.
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub enum_values: Option<Vec<Value>>,
+
+    /// Enum type property possible variants.
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
     pub enum_values: Option<Vec<String>>,
 
+    /// Names of the enum variants in the same order as [`Property::enum_values`]. Serialized as
+    /// `x-enum-varnames` vendor extension understood by code generators.
+    #[serde(rename = "x-enum-varnames", skip_serializing_if = "Option::is_none")]
+    pub enum_varnames: Option<Vec<String>>,
+
+    /// Descriptions of the enum variants in the same order as [`Property::enum_values`].
+    /// Serialized as `x-enum-descriptions` vendor extension understood by code generators.
+    #[serde(rename = "x-enum-descriptions", skip_serializing_if = "Option::is_none")]
+    pub enum_descriptions: Option<Vec<String>>,
+
+    /// Inclusive minimum value of number or integer property.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_number"
+    )]
+    pub minimum: Option<f64>,
+
+    /// Inclusive maximum value of number or integer property.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_number"
+    )]
+    pub maximum: Option<f64>,
+
     /// Example shown in UI of the value for richier documentation.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg(not(feature = "serde_json"))]
@@ -328,6 +715,8 @@ pub struct PropertyBuilder {
 
     format: Option<ComponentFormat>,
 
+    title: Option<String>,
+
     description: Option<String>,
 
     #[cfg(feature = "serde_json")]
@@ -336,8 +725,20 @@ pub struct PropertyBuilder {
     #[cfg(not(feature = "serde_json"))]
     default: Option<String>,
 
+    #[cfg(feature = "serde_json")]
+    enum_values: Option<Vec<Value>>,
+
+    #[cfg(not(feature = "serde_json"))]
     enum_values: Option<Vec<String>>,
 
+    enum_varnames: Option<Vec<String>>,
+
+    enum_descriptions: Option<Vec<String>>,
+
+    minimum: Option<f64>,
+
+    maximum: Option<f64>,
+
     #[cfg(not(feature = "serde_json"))]
     example: Option<String>,
 
@@ -354,7 +755,7 @@ pub struct PropertyBuilder {
 }
 
 from!(Property PropertyBuilder 
-    component_type, format, description, default, enum_values, example, deprecated, write_only, read_only, xml);
+    component_type, format, title, description, default, enum_values, enum_varnames, enum_descriptions, minimum, maximum, example, deprecated, write_only, read_only, xml);
 
 impl PropertyBuilder {
     new!(pub PropertyBuilder);
@@ -369,6 +770,11 @@ impl PropertyBuilder {
         set_value!(self format format)
     }
 
+    /// Add or change title of the property.
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        set_value!(self title title.map(|title| title.into()))
+    }
+
     /// Add or change description of the property. Markdown syntax is supported.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -386,7 +792,19 @@ impl PropertyBuilder {
         set_value!(self default default.map(|default| default.into()))
     }
 
+    /// Add or change enum property variants. Variants can be any values convertible to json
+    /// value e.g. strings or integers.
+    #[cfg(feature = "serde_json")]
+    pub fn enum_values<I: IntoIterator<Item = E>, E: Into<Value>>(
+        mut self,
+        enum_values: Option<I>,
+    ) -> Self {
+        set_value!(self enum_values
+            enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
+    }
+
     /// Add or change enum property variants.
+    #[cfg(not(feature = "serde_json"))]
     pub fn enum_values<I: IntoIterator<Item = E>, E: Into<String>>(
         mut self,
         enum_values: Option<I>,
@@ -395,6 +813,35 @@ impl PropertyBuilder {
             enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
     }
 
+    /// Add or change names of the enum variants. Names are given in same order as enum values.
+    pub fn enum_varnames<I: IntoIterator<Item = E>, E: Into<String>>(
+        mut self,
+        enum_varnames: Option<I>,
+    ) -> Self {
+        set_value!(self enum_varnames
+            enum_varnames.map(|names| names.into_iter().map(|name| name.into()).collect()))
+    }
+
+    /// Add or change descriptions of the enum variants. Descriptions are given in same order
+    /// as enum values.
+    pub fn enum_descriptions<I: IntoIterator<Item = E>, E: Into<String>>(
+        mut self,
+        enum_descriptions: Option<I>,
+    ) -> Self {
+        set_value!(self enum_descriptions
+            enum_descriptions.map(|descriptions| descriptions.into_iter().map(|description| description.into()).collect()))
+    }
+
+    /// Add or change inclusive minimum value of number or integer property.
+    pub fn minimum(mut self, minimum: Option<f64>) -> Self {
+        set_value!(self minimum minimum)
+    }
+
+    /// Add or change inclusive maximum value of number or integer property.
+    pub fn maximum(mut self, maximum: Option<f64>) -> Self {
+        set_value!(self maximum maximum)
+    }
+
     /// Add or change example shown in UI of the value for richier documentation.
     #[cfg(not(feature = "serde_json"))]
     pub fn example<I: Into<String>>(mut self, example: Option<I>) -> Self {
@@ -430,11 +877,22 @@ impl PropertyBuilder {
     to_array_builder!();
 
     build_fn!(pub Property 
-        component_type, format, description, default, enum_values, example, deprecated, write_only, read_only, xml);
+        component_type, format, title, description, default, enum_values, enum_varnames, enum_descriptions, minimum, maximum, example, deprecated, write_only, read_only, xml);
 }
 
 component_from_builder!(PropertyBuilder);
 
+/// Serialize number without fractional part as integer e.g. `0` instead of `0.0`.
+fn serialize_number<S: Serializer>(number: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> {
+    match number {
+        Some(number) if number.fract() == 0.0 && number.abs() < i64::MAX as f64 => {
+            serializer.serialize_i64(*number as i64)
+        }
+        Some(number) => serializer.serialize_f64(*number),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Implements subset of [OpenAPI Schema Object][schema] which allows 
 /// adding other [`Component`]s as **properties** to this [`Component`].
 /// 
@@ -456,6 +914,10 @@ pub struct Object {
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub properties: HashMap<String, Component>,
 
+    /// Title of the [`Object`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
     /// Description of the [`Object`]. Markdown syntax is supported.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -474,6 +936,24 @@ pub struct Object {
     #[cfg(not(feature = "serde_json"))]
     pub example: Option<String>,
 
+    /// Default value for the [`Object`] which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub default: Option<Value>,
+
+    /// Default value for the [`Object`] which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub default: Option<String>,
+
+    /// Write only [`Object`] will be only sent in _write_ requests like _POST, PUT_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_only: Option<bool>,
+
+    /// Read only [`Object`] will be only sent in _read_ requests like _GET_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+
     /// Additional [`Xml`] formatting of the [`Object`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xml: Option<Xml>,
@@ -504,6 +984,8 @@ pub struct ObjectBuilder {
 
     properties: HashMap<String, Component>,
 
+    title: Option<String>,
+
     description: Option<String>,
 
     deprecated: Option<Deprecated>,
@@ -514,6 +996,16 @@ pub struct ObjectBuilder {
     #[cfg(not(feature = "serde_json"))]
     example: Option<String>,
 
+    #[cfg(feature = "serde_json")]
+    default: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    default: Option<String>,
+
+    write_only: Option<bool>,
+
+    read_only: Option<bool>,
+
     xml: Option<Xml>,
 }
 
@@ -541,6 +1033,11 @@ impl ObjectBuilder {
         self
     }
 
+    /// Add or change title of the [`Object`].
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        set_value!(self title title.map(|title| title.into()))
+    }
+
     /// Add or change description of the property. Markdown syntax is supported.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -563,6 +1060,28 @@ impl ObjectBuilder {
         set_value!(self example example.map(|example| example.into()))
     }
 
+    /// Add or change default value for the [`Object`] which is provided when user has not provided the input.
+    #[cfg(feature = "serde_json")]
+    pub fn default(mut self, default: Option<Value>) -> Self {
+        set_value!(self default default)
+    }
+
+    /// Add or change default value for the [`Object`] which is provided when user has not provided the input.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn default<I: Into<String>>(mut self, default: Option<I>) -> Self {
+        set_value!(self default default.map(|default| default.into()))
+    }
+
+    /// Add or change write only flag for [`Object`].
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for [`Object`].
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
     /// Add or change additional [`Xml`] formatting of the [`Object`].
     pub fn xml(mut self, xml: Option<Xml>) -> Self {
         set_value!(self xml xml)
@@ -570,10 +1089,10 @@ impl ObjectBuilder {
     
     to_array_builder!();
 
-    build_fn!(pub Object component_type, required, properties, description, deprecated, example, xml);
+    build_fn!(pub Object component_type, required, properties, title, description, deprecated, example, default, write_only, read_only, xml);
 }
 
-from!(Object ObjectBuilder component_type, required, properties, description, deprecated, example, xml);
+from!(Object ObjectBuilder component_type, required, properties, title, description, deprecated, example, default, write_only, read_only, xml);
 component_from_builder!(ObjectBuilder);
 
 /// Implements [OpenAPI Reference Object][reference] that can be used to reference 
@@ -615,36 +1134,80 @@ impl From<Ref> for Component {
 
 impl ToArray for Ref {}
 
-builder! {
-    ArrayBuilder;
+/// Component represents [`Vec`] or [`slice`] type  of items.
+///
+/// See [`Component::Array`] for more details.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct Array {
+    /// Type will always be [`ComponentType::Array`]
+    #[serde(rename = "type")]
+    component_type: ComponentType,
 
-    /// Component represents [`Vec`] or [`slice`] type  of items.
-    ///
-    /// See [`Component::Array`] for more details.
-    #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
-    #[cfg_attr(feature = "debug", derive(Debug))]
-    #[serde(rename_all = "camelCase")]
-    pub struct Array {
-        /// Type will always be [`ComponentType::Array`]
-        #[serde(rename = "type")]
-        component_type: ComponentType,
+    /// Component representing the array items type.
+    pub items: Box<Component>,
 
-        /// Component representing the array items type.
-        pub items: Box<Component>,
+    /// Optional title of the array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 
-        /// Max length of the array.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub max_items: Option<usize>,
+    /// Optional description of the array. Markdown syntax is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 
-        /// Min lenght of the array.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub min_items: Option<usize>,
+    /// Max length of the array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
 
-        /// Xml format of the array.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub xml: Option<Xml>,
-    }
+    /// Min lenght of the array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<usize>,
+
+    /// Defines whether array items must be unique. Used with set types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_items: Option<bool>,
+
+    /// Components of positional items of the array. Used with tuple types in OpenAPI 3.1.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prefix_items: Vec<Component>,
+
+    /// Xml format of the array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xml: Option<Xml>,
+
+    /// Changes the [`Array`] deprecated status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecated>,
+
+    /// Default value for the array which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub default: Option<Value>,
+
+    /// Default value for the array which is provided when user has not provided the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub default: Option<String>,
+
+    /// Example shown in UI of the value for richier documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub example: Option<Value>,
+
+    /// Example shown in UI of the value for richier documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub example: Option<String>,
+
+    /// Write only array will be only sent in _write_ requests like _POST, PUT_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_only: Option<bool>,
+
+    /// Read only array will be only sent in _read_ requests like _GET_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
 }
 
 impl Array {
@@ -671,12 +1234,65 @@ impl Array {
     }
 }
 
+/// Builder for [`Array`] with chainable configuration methods to create a new [`Array`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ArrayBuilder {
+    component_type: ComponentType,
+
+    items: Box<Component>,
+
+    title: Option<String>,
+
+    description: Option<String>,
+
+    max_items: Option<usize>,
+
+    min_items: Option<usize>,
+
+    unique_items: Option<bool>,
+
+    prefix_items: Vec<Component>,
+
+    xml: Option<Xml>,
+
+    deprecated: Option<Deprecated>,
+
+    #[cfg(feature = "serde_json")]
+    default: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    default: Option<String>,
+
+    #[cfg(feature = "serde_json")]
+    example: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    example: Option<String>,
+
+    write_only: Option<bool>,
+
+    read_only: Option<bool>,
+}
+
 impl ArrayBuilder {
+    new!(pub ArrayBuilder);
+
     /// Set [`Component`] type for the [`Array`].
     pub fn items<I: Into<Component>>(mut self, component: I) -> Self {
         set_value!(self items Box::new(component.into()))
     }
 
+    /// Set optional title for [`Array`].
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        set_value!(self title title.map(|title| title.into()))
+    }
+
+    /// Set optional description for [`Array`]. Markdown syntax is supported.
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
     /// Set maximun allowed lenght for [`Array`].
     pub fn max_items(mut self, max_items: Option<usize>) -> Self {
         set_value!(self max_items max_items)
@@ -687,14 +1303,68 @@ impl ArrayBuilder {
         set_value!(self min_items min_items)
     }
 
+    /// Add [`Component`] of next positional item of the [`Array`].
+    pub fn prefix_item<I: Into<Component>>(mut self, component: I) -> Self {
+        self.prefix_items.push(component.into());
+
+        self
+    }
+
+    /// Set whether [`Array`] items must be unique.
+    pub fn unique_items(mut self, unique_items: Option<bool>) -> Self {
+        set_value!(self unique_items unique_items)
+    }
+
     /// Set [`Xml`] formatting for [`Array`].
     pub fn xml(mut self, xml: Option<Xml>) -> Self {
         set_value!(self xml xml)
     }
 
+    /// Add or change deprecated status for [`Array`].
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change default value for the array which is provided when user has not provided the input.
+    #[cfg(feature = "serde_json")]
+    pub fn default(mut self, default: Option<Value>) -> Self {
+        set_value!(self default default)
+    }
+
+    /// Add or change default value for the array which is provided when user has not provided the input.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn default<I: Into<String>>(mut self, default: Option<I>) -> Self {
+        set_value!(self default default.map(|default| default.into()))
+    }
+
+    /// Add or change example shown in UI of the value for richier documentation.
+    #[cfg(feature = "serde_json")]
+    pub fn example(mut self, example: Option<Value>) -> Self {
+        set_value!(self example example)
+    }
+
+    /// Add or change example shown in UI of the value for richier documentation.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn example<I: Into<String>>(mut self, example: Option<I>) -> Self {
+        set_value!(self example example.map(|example| example.into()))
+    }
+
+    /// Add or change write only flag for [`Array`].
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        set_value!(self write_only write_only)
+    }
+
+    /// Add or change read only flag for [`Array`].
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        set_value!(self read_only read_only)
+    }
+
     to_array_builder!();
+
+    build_fn!(pub Array component_type, items, title, description, max_items, min_items, unique_items, prefix_items, xml, deprecated, default, example, write_only, read_only);
 }
 
+from!(Array ArrayBuilder component_type, items, title, description, max_items, min_items, unique_items, prefix_items, xml, deprecated, default, example, write_only, read_only);
 component_from_builder!(ArrayBuilder);
 
 impl From<Array> for Component {
@@ -765,8 +1435,20 @@ pub enum ComponentFormat {
     /// ISO-8601 full date time [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14).
     #[serde(rename = "date-time")]
     DateTime,
+    /// ISO-8601 full time [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14).
+    Time,
+    /// ISO-8601 duration [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#appendix-A).
+    Duration,
     /// Hint to UI to obsucre input.
     Password,
+    /// Universally unique identifier [RFC4122](https://www.rfc-editor.org/rfc/rfc4122).
+    Uuid,
+    /// Uniform resource identifier [RFC3986](https://www.rfc-editor.org/rfc/rfc3986).
+    Uri,
+    /// Any other format e.g. `email`, `hostname` or `ipv4`. OpenAPI allows arbitrary format
+    /// strings which tools not supporting the format will ignore.
+    #[serde(untagged)]
+    Custom(String),
 }
 
 #[cfg(test)]
@@ -902,6 +1584,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn property_with_custom_format_serializes_and_deserializes() {
+        let property = PropertyBuilder::new()
+            .component_type(ComponentType::String)
+            .format(Some(ComponentFormat::Custom("iban".to_string())))
+            .build();
+
+        let value_string = serde_json::to_string(&property).unwrap();
+        assert_eq!(value_string, r#"{"type":"string","format":"iban"}"#);
+
+        let format: ComponentFormat = serde_json::from_str(r#""date-time""#).unwrap();
+        assert!(matches!(format, ComponentFormat::DateTime));
+
+        let format: ComponentFormat = serde_json::from_str(r#""iban""#).unwrap();
+        assert!(matches!(format, ComponentFormat::Custom(format) if format == "iban"));
+    }
+
+    #[test]
+    fn free_form_component_serializes_and_deserializes() {
+        let component: Component = FreeFormBuilder::new().into();
+
+        let value_string = serde_json::to_string(&component).unwrap();
+        assert_eq!(value_string, "{}");
+
+        let component: Component = serde_json::from_str(&value_string).unwrap();
+        assert!(matches!(component, Component::FreeForm(_)));
+
+        let component: Component = serde_json::from_str(r#"{"type":"string"}"#).unwrap();
+        assert!(matches!(component, Component::Property(_)));
+    }
+
     fn get_json_path<'a>(value: &'a Value, path: &str) -> &'a Value {
         path.split('.').into_iter().fold(value, |acc, fragment| {
             acc.get(fragment).unwrap_or(&serde_json::value::Value::Null)
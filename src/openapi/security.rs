@@ -20,6 +20,9 @@ use super::{build_fn, builder, from, new};
 /// make the security requirements available for that operation. Only one of the requirements must be
 /// satisfied.
 ///
+/// Single security requirement can combine multiple security schemes in which case all of
+/// them must be satisfied. See [`SecurityRequirement::add`].
+///
 /// [security]: https://spec.openapis.org/oas/latest.html#security-requirement-object
 /// [path]: ../../attr.path.html
 /// [openapi]: ../../derive.OpenApi.html
@@ -66,6 +69,31 @@ impl SecurityRequirement {
             })),
         }
     }
+
+    /// Add [`SecurityScheme`] with scopes to this [`SecurityRequirement`].
+    ///
+    /// All of the security schemes in the [`SecurityRequirement`] must be satisfied for the
+    /// requirement to be satisfied. Accepts same parameters as [`SecurityRequirement::new`].
+    ///
+    /// # Examples
+    ///
+    /// Require both api key and oauth2 scope.
+    /// ```rust
+    /// # use utoipa::openapi::security::SecurityRequirement;
+    /// SecurityRequirement::new("api_key", [] as [&str; 0]).add("api_oauth2_flow", ["read:items"]);
+    /// ```
+    pub fn add<N: Into<String>, S: IntoIterator<Item = I>, I: Into<String>>(
+        mut self,
+        name: N,
+        scopes: S,
+    ) -> Self {
+        self.value.insert(
+            name.into(),
+            scopes.into_iter().map(Into::<String>::into).collect(),
+        );
+
+        self
+    }
 }
 
 /// OpenAPI [security scheme][security] for path operations.
@@ -928,6 +956,15 @@ mod tests {
         };
     }
 
+    test_fn! {
+        security_requirement_with_multiple_schemes:
+        SecurityRequirement::new("api_key", [] as [&str; 0]).add("oauth", ["read", "write"]);
+        r###"{
+  "api_key": [],
+  "oauth": ["read", "write"]
+}"###
+    }
+
     test_fn! {
     security_schema_correct_http_bearer_json:
     SecurityScheme::Http(
@@ -0,0 +1,672 @@
+//! Implements runtime validation of JSON values against [`Component`]s.
+//!
+//! Validation can be used to verify that a value e.g. output of a handler actually conforms
+//! to the documented [`Component`]. References to other components are resolved from given
+//! [`Components`]. Each found violation is reported with [JSON Pointer][json_pointer] to the
+//! invalid value.
+//!
+//! Validation is available with **validation** feature.
+//!
+//! [json_pointer]: https://www.rfc-editor.org/rfc/rfc6901
+use std::{error::Error, fmt::Display};
+
+use serde_json::Value;
+
+use super::schema::{
+    Array, Component, ComponentFormat, ComponentType, Components, Object, OneOf, Property,
+};
+
+/// Validate `value` against `component` resolving references from `components`.
+///
+/// Returns all found [`ValidationError`]s or `Ok(())` if the value conforms to the component.
+///
+/// Validation rules follow the serialization of derived components. Properties missing from
+/// [`Object::required`] are allowed to be `null` since `Option` fields are serialized as `null`.
+/// Value is valid against [`OneOf`] component if it is valid against exactly one of its items.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use utoipa::openapi::{Components, ComponentType, ObjectBuilder, Property};
+/// # use utoipa::openapi::validation;
+/// let pet = ObjectBuilder::new()
+///     .property("name", Property::new(ComponentType::String))
+///     .required("name")
+///     .into();
+///
+/// let errors = validation::validate(&pet, &Components::new(), &json!({"name": 1})).unwrap_err();
+///
+/// assert_eq!(errors[0].pointer, "/name");
+/// assert_eq!(errors[0].to_string(), "/name: expected string but found number");
+/// ```
+pub fn validate(
+    component: &Component,
+    components: &Components,
+    value: &Value,
+) -> Result<(), Vec<ValidationError>> {
+    let mut validator = Validator {
+        components,
+        errors: Vec::new(),
+    };
+    validator.validate(component, value, &mut String::new());
+
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors)
+    }
+}
+
+/// Single violation found by [`validate`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ValidationError {
+    /// [JSON Pointer][json_pointer] to the invalid value. Empty string points to the root value.
+    ///
+    /// [json_pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    pub pointer: String,
+
+    /// Kind of the violation.
+    pub kind: ValidationErrorKind,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.kind)
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Kinds of violations reported by [`validate`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Debug)]
+pub enum ValidationErrorKind {
+    /// Value is of different type than the [`ComponentType`] of the component.
+    InvalidType {
+        /// Expected type of the value.
+        expected: &'static str,
+        /// Actual type of the value.
+        found: &'static str,
+    },
+    /// Object is missing required property.
+    MissingRequired(String),
+    /// Value is not any of the [`Property::enum_values`].
+    InvalidEnumValue,
+    /// Value does not match the [`ComponentFormat`] of the property.
+    InvalidFormat(String),
+    /// Number is less than [`Property::minimum`].
+    Minimum(f64),
+    /// Number is greater than [`Property::maximum`].
+    Maximum(f64),
+    /// Array has less items than [`Array::min_items`].
+    MinItems(usize),
+    /// Array has more items than [`Array::max_items`].
+    MaxItems(usize),
+    /// Array has duplicate items while [`Array::unique_items`] is set.
+    UniqueItems,
+    /// Value is not valid against any of the [`OneOf`] items.
+    NoMatchingOneOf,
+    /// Value is valid against more than one of the [`OneOf`] items. Contains the number of
+    /// matching items.
+    MultipleMatchingOneOf(usize),
+    /// Referenced component was not found from [`Components`].
+    UnresolvedRef(String),
+}
+
+impl Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidType { expected, found } => {
+                write!(f, "expected {} but found {}", expected, found)
+            }
+            Self::MissingRequired(property) => {
+                write!(f, "missing required property `{}`", property)
+            }
+            Self::InvalidEnumValue => write!(f, "value is not one of the enum values"),
+            Self::InvalidFormat(format) => write!(f, "value is not valid `{}`", format),
+            Self::Minimum(minimum) => write!(f, "value is less than minimum {}", minimum),
+            Self::Maximum(maximum) => write!(f, "value is greater than maximum {}", maximum),
+            Self::MinItems(min_items) => write!(f, "array has less than {} items", min_items),
+            Self::MaxItems(max_items) => write!(f, "array has more than {} items", max_items),
+            Self::UniqueItems => write!(f, "array items are not unique"),
+            Self::NoMatchingOneOf => write!(f, "value does not match any of the oneOf components"),
+            Self::MultipleMatchingOneOf(matches) => write!(
+                f,
+                "value matches {} of the oneOf components, expected exactly one",
+                matches
+            ),
+            Self::UnresolvedRef(reference) => write!(f, "unresolved reference `{}`", reference),
+        }
+    }
+}
+
+struct Validator<'c> {
+    components: &'c Components,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator<'_> {
+    fn validate(&mut self, component: &Component, value: &Value, pointer: &mut String) {
+        match component {
+            Component::Property(property) => self.validate_property(property, value, pointer),
+            Component::Object(object) => self.validate_object(object, value, pointer),
+            Component::Array(array) => self.validate_array(array, value, pointer),
+            Component::OneOf(one_of) => self.validate_one_of(one_of, value, pointer),
+            // free-form component accepts any value
+            Component::FreeForm(_) => (),
+            Component::Ref(reference) => {
+                let component = reference
+                    .ref_location
+                    .strip_prefix("#/components/schemas/")
+                    .and_then(|name| self.components.schemas.get(name));
+
+                match component {
+                    Some(component) => self.validate(component, value, pointer),
+                    None => self.error(
+                        pointer,
+                        ValidationErrorKind::UnresolvedRef(reference.ref_location.clone()),
+                    ),
+                }
+            }
+        }
+    }
+
+    fn validate_property(&mut self, property: &Property, value: &Value, pointer: &str) {
+        if !self.validate_type(&property.component_type, value, pointer) {
+            return;
+        }
+
+        if let Some(enum_values) = &property.enum_values {
+            if !enum_values.contains(value) {
+                self.error(pointer, ValidationErrorKind::InvalidEnumValue);
+            }
+        }
+
+        if let Some(format) = &property.format {
+            if !is_valid_format(format, value) {
+                let format = serde_json::to_value(format)
+                    .ok()
+                    .and_then(|format| format.as_str().map(ToString::to_string))
+                    .unwrap_or_default();
+                self.error(pointer, ValidationErrorKind::InvalidFormat(format));
+            }
+        }
+
+        if let Some(number) = value.as_f64() {
+            match (property.minimum, property.maximum) {
+                (Some(minimum), _) if number < minimum => {
+                    self.error(pointer, ValidationErrorKind::Minimum(minimum))
+                }
+                (_, Some(maximum)) if number > maximum => {
+                    self.error(pointer, ValidationErrorKind::Maximum(maximum))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn validate_object(&mut self, object: &Object, value: &Value, pointer: &mut String) {
+        let values = match value {
+            Value::Object(values) => values,
+            _ => return self.invalid_type("object", value, pointer),
+        };
+
+        for required in &object.required {
+            if !values.contains_key(required) {
+                self.error(
+                    pointer,
+                    ValidationErrorKind::MissingRequired(required.clone()),
+                );
+            }
+        }
+
+        let mut properties = object.properties.iter().collect::<Vec<_>>();
+        properties.sort_by_key(|(name, _)| *name);
+
+        for (name, component) in properties {
+            let value = match values.get(name) {
+                Some(Value::Null) if !object.required.contains(name) => continue,
+                Some(value) => value,
+                None => continue,
+            };
+
+            let len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+            self.validate(component, value, pointer);
+            pointer.truncate(len);
+        }
+    }
+
+    fn validate_array(&mut self, array: &Array, value: &Value, pointer: &mut String) {
+        let items = match value {
+            Value::Array(items) => items,
+            _ => return self.invalid_type("array", value, pointer),
+        };
+
+        match (array.min_items, array.max_items) {
+            (Some(min_items), _) if items.len() < min_items => {
+                self.error(pointer, ValidationErrorKind::MinItems(min_items))
+            }
+            (_, Some(max_items)) if items.len() > max_items => {
+                self.error(pointer, ValidationErrorKind::MaxItems(max_items))
+            }
+            _ => (),
+        }
+
+        if array.unique_items == Some(true)
+            && items
+                .iter()
+                .enumerate()
+                .any(|(index, item)| items[..index].contains(item))
+        {
+            self.error(pointer, ValidationErrorKind::UniqueItems);
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            let component = array.prefix_items.get(index).unwrap_or(&array.items);
+
+            let len = pointer.len();
+            pointer.push('/');
+            pointer.push_str(&index.to_string());
+            self.validate(component, item, pointer);
+            pointer.truncate(len);
+        }
+    }
+
+    fn validate_one_of(&mut self, one_of: &OneOf, value: &Value, pointer: &mut String) {
+        let matches = one_of
+            .items
+            .iter()
+            .filter(|item| {
+                let mut validator = Validator {
+                    components: self.components,
+                    errors: Vec::new(),
+                };
+                validator.validate(item, value, pointer);
+
+                validator.errors.is_empty()
+            })
+            .count();
+
+        match matches {
+            0 => self.error(pointer, ValidationErrorKind::NoMatchingOneOf),
+            1 => (),
+            matches => self.error(pointer, ValidationErrorKind::MultipleMatchingOneOf(matches)),
+        }
+    }
+
+    /// Validate type of the value returning `true` if value is of correct type.
+    fn validate_type(
+        &mut self,
+        component_type: &ComponentType,
+        value: &Value,
+        pointer: &str,
+    ) -> bool {
+        let (expected, valid) = match component_type {
+            ComponentType::Object => ("object", value.is_object()),
+            ComponentType::String => ("string", value.is_string()),
+            ComponentType::Integer => (
+                "integer",
+                value.is_i64()
+                    || value.is_u64()
+                    || matches!(value.as_f64(), Some(number) if number.fract() == 0.0),
+            ),
+            ComponentType::Number => ("number", value.is_number()),
+            ComponentType::Boolean => ("boolean", value.is_boolean()),
+            ComponentType::Array => ("array", value.is_array()),
+        };
+
+        if !valid {
+            self.invalid_type(expected, value, pointer);
+        }
+
+        valid
+    }
+
+    fn invalid_type(&mut self, expected: &'static str, value: &Value, pointer: &str) {
+        let found = match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+
+        self.error(
+            pointer,
+            ValidationErrorKind::InvalidType { expected, found },
+        )
+    }
+
+    fn error(&mut self, pointer: &str, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError {
+            pointer: pointer.to_string(),
+            kind,
+        })
+    }
+}
+
+/// Check that value matches the format. Formats which cannot be verified from the value
+/// alone e.g. [`ComponentFormat::Password`] are always valid.
+fn is_valid_format(format: &ComponentFormat, value: &Value) -> bool {
+    match (format, value) {
+        (ComponentFormat::Int32, Value::Number(number)) => number
+            .as_i64()
+            .map(|number| i32::try_from(number).is_ok())
+            .unwrap_or_else(|| number.is_f64()),
+        (ComponentFormat::Int64, Value::Number(number)) => !number.is_u64() || number.is_i64(),
+        (ComponentFormat::Date, Value::String(value)) => is_date(value),
+        (ComponentFormat::DateTime, Value::String(value)) => value
+            .split_once(['T', 't', ' '])
+            .map(|(date, time)| is_date(date) && is_time(time))
+            .unwrap_or(false),
+        (ComponentFormat::Time, Value::String(value)) => is_time(value),
+        (ComponentFormat::Uuid, Value::String(value)) => is_uuid(value),
+        (ComponentFormat::Uri, Value::String(value)) => is_uri(value),
+        (ComponentFormat::Byte, Value::String(value)) => is_base64(value),
+        _ => true,
+    }
+}
+
+fn is_digits(value: &str, len: usize) -> bool {
+    value.len() == len && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn is_in_range(value: &str, min: u32, max: u32) -> bool {
+    value
+        .parse::<u32>()
+        .map(|value| (min..=max).contains(&value))
+        .unwrap_or(false)
+}
+
+/// Full date e.g. `2022-07-29`.
+fn is_date(value: &str) -> bool {
+    match value.split('-').collect::<Vec<_>>()[..] {
+        [year, month, day] => {
+            is_digits(year, 4)
+                && is_digits(month, 2)
+                && is_digits(day, 2)
+                && is_in_range(month, 1, 12)
+                && is_in_range(day, 1, 31)
+        }
+        _ => false,
+    }
+}
+
+/// Time with optional fraction of seconds and optional offset e.g. `10:15:00.5+02:00`.
+fn is_time(value: &str) -> bool {
+    let (time, offset) = match value.find(['Z', 'z', '+', '-']) {
+        Some(index) => value.split_at(index),
+        None => (value, ""),
+    };
+    let time = match time.split_once('.') {
+        Some((time, fraction)) if is_digits(fraction, fraction.len()) && !fraction.is_empty() => {
+            time
+        }
+        Some(_) => return false,
+        None => time,
+    };
+
+    let is_valid_time = match time.split(':').collect::<Vec<_>>()[..] {
+        [hour, minute, second] => {
+            is_digits(hour, 2)
+                && is_digits(minute, 2)
+                && is_digits(second, 2)
+                && is_in_range(hour, 0, 23)
+                && is_in_range(minute, 0, 59)
+                && is_in_range(second, 0, 60)
+        }
+        _ => false,
+    };
+    let is_valid_offset = match offset {
+        "" | "Z" | "z" => true,
+        offset => match offset[1..].split(':').collect::<Vec<_>>()[..] {
+            [hour, minute] => {
+                is_digits(hour, 2)
+                    && is_digits(minute, 2)
+                    && is_in_range(hour, 0, 23)
+                    && is_in_range(minute, 0, 59)
+            }
+            _ => false,
+        },
+    };
+
+    is_valid_time && is_valid_offset
+}
+
+/// Hyphenated uuid e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+fn is_uuid(value: &str) -> bool {
+    let groups = value.split('-').collect::<Vec<_>>();
+
+    groups.len() == 5
+        && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, len)| {
+            group.len() == len && group.bytes().all(|byte| byte.is_ascii_hexdigit())
+        })
+}
+
+/// Uri with scheme e.g. `https://localhost` or `urn:isbn:0451450523`.
+fn is_uri(value: &str) -> bool {
+    match value.split_once(':') {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !value.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+/// Standard base64 with padding.
+// `usize::is_multiple_of` is not available on older supported compilers.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+fn is_base64(value: &str) -> bool {
+    let data = value.trim_end_matches('=');
+
+    value.len() % 4 == 0
+        && value.len() - data.len() <= 2
+        && data
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'/')
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::{
+        schema::{
+            ArrayBuilder, ComponentsBuilder, ObjectBuilder, OneOfBuilder, PropertyBuilder, Ref,
+        },
+        ComponentFormat, ComponentType, Property, ToArray,
+    };
+
+    use super::*;
+
+    fn pet() -> Component {
+        ObjectBuilder::new()
+            .property(
+                "id",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::Integer)
+                    .format(Some(ComponentFormat::Int64))
+                    .minimum(Some(0f64)),
+            )
+            .required("id")
+            .property("name", Property::new(ComponentType::String))
+            .required("name")
+            .property(
+                "status",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .enum_values(Some(["Available", "Sold"])),
+            )
+            .property(
+                "born",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::String)
+                    .format(Some(ComponentFormat::Date)),
+            )
+            .property(
+                "tags",
+                ArrayBuilder::new()
+                    .items(Property::new(ComponentType::String))
+                    .max_items(Some(2))
+                    .unique_items(Some(true)),
+            )
+            .into()
+    }
+
+    fn errors(component: &Component, components: &Components, value: Value) -> Vec<String> {
+        validate(component, components, &value)
+            .err()
+            .unwrap_or_default()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn validate_valid_object() {
+        let value = json!({
+            "id": 1,
+            "name": "doggo",
+            "status": "Sold",
+            "born": "2020-02-29",
+            "tags": ["good", "boy"]
+        });
+
+        assert_eq!(validate(&pet(), &Components::new(), &value), Ok(()));
+    }
+
+    #[test]
+    fn validate_optional_property_with_null_value() {
+        let value = json!({ "id": 1, "name": "doggo", "status": null });
+
+        assert_eq!(validate(&pet(), &Components::new(), &value), Ok(()));
+    }
+
+    #[test]
+    fn validate_invalid_object_reports_json_pointers() {
+        let value = json!({
+            "id": -1,
+            "status": "Lost",
+            "born": "2020-13-01",
+            "tags": ["good", 1, "good"]
+        });
+
+        assert_eq!(
+            errors(&pet(), &Components::new(), value),
+            [
+                ": missing required property `name`",
+                "/born: value is not valid `date`",
+                "/id: value is less than minimum 0",
+                "/status: value is not one of the enum values",
+                "/tags: array has more than 2 items",
+                "/tags: array items are not unique",
+                "/tags/1: expected string but found number",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_resolves_refs() {
+        let components = ComponentsBuilder::new().component("Pet", pet()).build();
+        let pets: Component = Ref::from_component_name("Pet").to_array().into();
+
+        assert_eq!(
+            errors(&pets, &components, json!([{ "id": 1, "name": 1 }])),
+            ["/0/name: expected string but found number"]
+        );
+        assert_eq!(
+            errors(
+                &Ref::from_component_name("Owner").into(),
+                &components,
+                json!({})
+            ),
+            [": unresolved reference `#/components/schemas/Owner`"]
+        );
+    }
+
+    #[test]
+    fn validate_one_of() {
+        let one_of: Component = OneOfBuilder::new()
+            .item(Property::new(ComponentType::Integer))
+            .item(Property::new(ComponentType::Boolean))
+            .into();
+
+        assert_eq!(validate(&one_of, &Components::new(), &json!(true)), Ok(()));
+        assert_eq!(
+            errors(&one_of, &Components::new(), json!("true")),
+            [": value does not match any of the oneOf components"]
+        );
+
+        let overlapping: Component = OneOfBuilder::new()
+            .item(Property::new(ComponentType::Integer))
+            .item(Property::new(ComponentType::Number))
+            .into();
+
+        assert_eq!(validate(&overlapping, &Components::new(), &json!(1.5)), Ok(()));
+        assert_eq!(
+            errors(&overlapping, &Components::new(), json!(1)),
+            [": value matches 2 of the oneOf components, expected exactly one"]
+        );
+    }
+
+    #[test]
+    fn validate_escapes_json_pointer() {
+        let component: Component = ObjectBuilder::new()
+            .property("a/b~c", Property::new(ComponentType::Boolean))
+            .into();
+
+        assert_eq!(
+            errors(&component, &Components::new(), json!({ "a/b~c": 1 })),
+            ["/a~1b~0c: expected boolean but found number"]
+        );
+    }
+
+    #[test]
+    fn validate_formats() {
+        let valid = [
+            (ComponentFormat::Int32, json!(i32::MAX)),
+            (ComponentFormat::DateTime, json!("2022-07-29T10:15:00.123Z")),
+            (ComponentFormat::DateTime, json!("2022-07-29T10:15:00")),
+            (ComponentFormat::Time, json!("10:15:00+02:00")),
+            (
+                ComponentFormat::Uuid,
+                json!("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            ),
+            (ComponentFormat::Uri, json!("https://localhost/pets")),
+            (ComponentFormat::Byte, json!("dXRvaXBh")),
+            (ComponentFormat::Password, json!("secret")),
+        ];
+        let invalid = [
+            (ComponentFormat::Int32, json!(i64::from(i32::MAX) + 1)),
+            (ComponentFormat::DateTime, json!("2022-07-29")),
+            (ComponentFormat::Time, json!("25:00:00")),
+            (ComponentFormat::Uuid, json!("67e55044-10b1-426f-9247")),
+            (ComponentFormat::Uri, json!("localhost")),
+            (ComponentFormat::Byte, json!("dXRvaXB")),
+        ];
+
+        for (format, value) in valid {
+            assert!(
+                is_valid_format(&format, &value),
+                "{} should be valid",
+                value
+            );
+        }
+        for (format, value) in invalid {
+            assert!(
+                !is_valid_format(&format, &value),
+                "{} should be invalid",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,778 @@
+//! Implements [`PartialComponent`] for Rust primitive and standard library types.
+//!
+//! Also contains the hidden helpers used by the derive macros to resolve the component of a
+//! type at compile time. Types implementing [`PartialComponent`] are resolved with it and
+//! other types are referenced by their name.
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+    marker::PhantomData,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use crate::{
+    openapi::{
+        schema::Component, xml::Xml, Array, ComponentFormat, ComponentType, Deprecated,
+        FreeFormBuilder, ObjectBuilder, OneOfBuilder, PropertyBuilder, Ref,
+    },
+    PartialComponent,
+};
+
+macro_rules! impl_partial_component {
+    ( $( $ty:ty ),* => $component_type:expr, $format:expr ) => {
+        impl_partial_component!($( $ty ),* => $component_type, $format, None, None);
+    };
+    ( $( $ty:ty ),* => $component_type:expr, $format:expr, $minimum:expr, $maximum:expr ) => {
+        $(
+            impl PartialComponent for $ty {
+                fn partial_component() -> Component {
+                    PropertyBuilder::new()
+                        .component_type($component_type)
+                        .format($format)
+                        .minimum($minimum)
+                        .maximum($maximum)
+                        .into()
+                }
+            }
+        )*
+    };
+}
+
+// `usize_format_int64` takes precedence so that enabling both features stays additive.
+#[cfg(all(feature = "usize_format_int32", not(feature = "usize_format_int64")))]
+const USIZE_FORMAT: Option<ComponentFormat> = Some(ComponentFormat::Int32);
+#[cfg(feature = "usize_format_int64")]
+const USIZE_FORMAT: Option<ComponentFormat> = Some(ComponentFormat::Int64);
+#[cfg(not(any(feature = "usize_format_int32", feature = "usize_format_int64")))]
+const USIZE_FORMAT: Option<ComponentFormat> = None;
+
+// Unsigned types use the narrowest format which can hold all of their values. `u64` and wider
+// types have no format since they do not fit to `int64`. Bounds are set when representable.
+impl_partial_component!(String, str, char => ComponentType::String, None);
+impl_partial_component!(bool => ComponentType::Boolean, None);
+impl_partial_component!(i8, NonZeroI8 => ComponentType::Integer, Some(ComponentFormat::Int32), Some(i8::MIN.into()), Some(i8::MAX.into()));
+impl_partial_component!(i16, NonZeroI16 => ComponentType::Integer, Some(ComponentFormat::Int32), Some(i16::MIN.into()), Some(i16::MAX.into()));
+impl_partial_component!(i32, NonZeroI32 => ComponentType::Integer, Some(ComponentFormat::Int32));
+impl_partial_component!(i64, NonZeroI64 => ComponentType::Integer, Some(ComponentFormat::Int64));
+impl_partial_component!(i128, NonZeroI128 => ComponentType::Integer, None);
+impl_partial_component!(isize, NonZeroIsize => ComponentType::Integer, USIZE_FORMAT);
+impl_partial_component!(u8 => ComponentType::Integer, Some(ComponentFormat::Int32), Some(0.0), Some(u8::MAX.into()));
+impl_partial_component!(u16 => ComponentType::Integer, Some(ComponentFormat::Int32), Some(0.0), Some(u16::MAX.into()));
+impl_partial_component!(u32 => ComponentType::Integer, Some(ComponentFormat::Int64), Some(0.0), Some(u32::MAX.into()));
+impl_partial_component!(u64, u128 => ComponentType::Integer, None, Some(0.0), None);
+impl_partial_component!(usize => ComponentType::Integer, USIZE_FORMAT, Some(0.0), None);
+impl_partial_component!(NonZeroU8 => ComponentType::Integer, Some(ComponentFormat::Int32), Some(1.0), Some(u8::MAX.into()));
+impl_partial_component!(NonZeroU16 => ComponentType::Integer, Some(ComponentFormat::Int32), Some(1.0), Some(u16::MAX.into()));
+impl_partial_component!(NonZeroU32 => ComponentType::Integer, Some(ComponentFormat::Int64), Some(1.0), Some(u32::MAX.into()));
+impl_partial_component!(NonZeroU64, NonZeroU128 => ComponentType::Integer, None, Some(1.0), None);
+impl_partial_component!(NonZeroUsize => ComponentType::Integer, USIZE_FORMAT, Some(1.0), None);
+impl_partial_component!(f32, f64 => ComponentType::Number, Some(ComponentFormat::Float));
+
+macro_rules! impl_partial_component_transparent {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl<T: PartialComponent + ?Sized> PartialComponent for $ty {
+                fn partial_component() -> Component {
+                    T::partial_component()
+                }
+
+                fn inline_partial_component() -> Component {
+                    T::inline_partial_component()
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_component_transparent!(
+    &T,
+    &mut T,
+    Box<T>,
+    Rc<T>,
+    Arc<T>,
+    RefCell<T>,
+    Cell<T>,
+    Mutex<T>,
+    RwLock<T>
+);
+
+impl<T: PartialComponent> PartialComponent for Option<T> {
+    fn partial_component() -> Component {
+        T::partial_component()
+    }
+
+    fn inline_partial_component() -> Component {
+        T::inline_partial_component()
+    }
+}
+
+impl<T: PartialComponent + ToOwned + ?Sized> PartialComponent for Cow<'_, T> {
+    fn partial_component() -> Component {
+        T::partial_component()
+    }
+
+    fn inline_partial_component() -> Component {
+        T::inline_partial_component()
+    }
+}
+
+macro_rules! impl_partial_component_array {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl<T: PartialComponent> PartialComponent for $ty {
+                fn partial_component() -> Component {
+                    Array::new(T::partial_component()).into()
+                }
+
+                fn inline_partial_component() -> Component {
+                    Array::new(T::inline_partial_component()).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_component_array!(Vec<T>, VecDeque<T>, LinkedList<T>, [T]);
+
+/// Fixed size arrays have both minimum and maximum number of items set to the size of the array.
+fn fixed_size_array(items: Component, len: usize) -> Component {
+    Array::new(items)
+        .to_builder()
+        .min_items(Some(len))
+        .max_items(Some(len))
+        .into()
+}
+
+impl<T: PartialComponent, const N: usize> PartialComponent for [T; N] {
+    fn partial_component() -> Component {
+        fixed_size_array(T::partial_component(), N)
+    }
+
+    fn inline_partial_component() -> Component {
+        fixed_size_array(T::inline_partial_component(), N)
+    }
+}
+
+/// Sets are documented as arrays of unique items.
+fn unique_items_array(items: Component) -> Component {
+    Array::new(items)
+        .to_builder()
+        .unique_items(Some(true))
+        .into()
+}
+
+impl<T: PartialComponent, S> PartialComponent for HashSet<T, S> {
+    fn partial_component() -> Component {
+        unique_items_array(T::partial_component())
+    }
+
+    fn inline_partial_component() -> Component {
+        unique_items_array(T::inline_partial_component())
+    }
+}
+
+impl<T: PartialComponent> PartialComponent for BTreeSet<T> {
+    fn partial_component() -> Component {
+        unique_items_array(T::partial_component())
+    }
+
+    fn inline_partial_component() -> Component {
+        unique_items_array(T::inline_partial_component())
+    }
+}
+
+impl<K, V, S> PartialComponent for HashMap<K, V, S> {
+    fn partial_component() -> Component {
+        ObjectBuilder::new().into()
+    }
+}
+
+impl<K, V> PartialComponent for BTreeMap<K, V> {
+    fn partial_component() -> Component {
+        ObjectBuilder::new().into()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T: PartialComponent, S> PartialComponent for indexmap::IndexSet<T, S> {
+    fn partial_component() -> Component {
+        unique_items_array(T::partial_component())
+    }
+
+    fn inline_partial_component() -> Component {
+        unique_items_array(T::inline_partial_component())
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> PartialComponent for indexmap::IndexMap<K, V, S> {
+    fn partial_component() -> Component {
+        ObjectBuilder::new().into()
+    }
+}
+
+/// Tuples are serialized to fixed size arrays by serde. Items are given as pairs of the type name
+/// and the component of each position. The distinct types of the items are documented as `oneOf`
+/// array items and with **openapi_31** feature the positional items also as `prefixItems`. Tuples
+/// consisting of a single type are documented with that type as array items.
+fn tuple_component(items: &[(&'static str, Component)]) -> Component {
+    let mut unique_items: Vec<&(&str, Component)> = Vec::with_capacity(items.len());
+    for item in items {
+        if !unique_items
+            .iter()
+            .any(|(type_name, _)| *type_name == item.0)
+        {
+            unique_items.push(item)
+        }
+    }
+
+    let array_items: Component = match unique_items[..] {
+        [(_, component)] => component.clone(),
+        _ => unique_items
+            .into_iter()
+            .fold(OneOfBuilder::new(), |one_of, (_, component)| {
+                one_of.item(component.clone())
+            })
+            .into(),
+    };
+
+    let array = Array::new(array_items)
+        .to_builder()
+        .min_items(Some(items.len()))
+        .max_items(Some(items.len()));
+
+    #[cfg(feature = "openapi_31")]
+    let array = items.iter().fold(array, |array, (_, component)| {
+        array.prefix_item(component.clone())
+    });
+
+    array.into()
+}
+
+macro_rules! impl_partial_component_tuple {
+    ( $( $ty:ident ),+ ) => {
+        impl<$( $ty: PartialComponent ),+> PartialComponent for ( $( $ty, )+ ) {
+            fn partial_component() -> Component {
+                tuple_component(&[ $( (std::any::type_name::<$ty>(), $ty::partial_component()) ),+ ])
+            }
+
+            fn inline_partial_component() -> Component {
+                tuple_component(&[ $( (std::any::type_name::<$ty>(), $ty::inline_partial_component()) ),+ ])
+            }
+        }
+    };
+}
+
+impl_partial_component_tuple!(T1);
+impl_partial_component_tuple!(T1, T2);
+impl_partial_component_tuple!(T1, T2, T3);
+impl_partial_component_tuple!(T1, T2, T3, T4);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6, T7);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+impl_partial_component_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Format of chrono types is only documented with `chrono_types_with_format` feature.
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+fn chrono_format(format: ComponentFormat) -> Option<ComponentFormat> {
+    if cfg!(feature = "chrono_types_with_format") {
+        Some(format)
+    } else {
+        None
+    }
+}
+
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+impl<Tz: chrono::TimeZone> PartialComponent for chrono::DateTime<Tz> {
+    fn partial_component() -> Component {
+        PropertyBuilder::new()
+            .component_type(ComponentType::String)
+            .format(chrono_format(ComponentFormat::DateTime))
+            .into()
+    }
+}
+
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+#[allow(deprecated)]
+impl<Tz: chrono::TimeZone> PartialComponent for chrono::Date<Tz> {
+    fn partial_component() -> Component {
+        PropertyBuilder::new()
+            .component_type(ComponentType::String)
+            .format(chrono_format(ComponentFormat::Date))
+            .into()
+    }
+}
+
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+impl_partial_component!(chrono::NaiveDateTime => ComponentType::String, chrono_format(ComponentFormat::DateTime));
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+impl_partial_component!(chrono::NaiveDate => ComponentType::String, chrono_format(ComponentFormat::Date));
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+impl_partial_component!(chrono::NaiveTime => ComponentType::String, chrono_format(ComponentFormat::Time));
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+impl_partial_component!(chrono::Duration => ComponentType::String, chrono_format(ComponentFormat::Duration));
+
+// `Weekday` is serialized with the short names of the days.
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+impl PartialComponent for chrono::Weekday {
+    fn partial_component() -> Component {
+        PropertyBuilder::new()
+            .component_type(ComponentType::String)
+            .enum_values(Some(["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]))
+            .into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl_partial_component!(time::OffsetDateTime, time::PrimitiveDateTime => ComponentType::String, Some(ComponentFormat::DateTime));
+#[cfg(feature = "time")]
+impl_partial_component!(time::Date => ComponentType::String, Some(ComponentFormat::Date));
+#[cfg(feature = "time")]
+impl_partial_component!(time::Time => ComponentType::String, Some(ComponentFormat::Time));
+#[cfg(feature = "time")]
+impl_partial_component!(time::Duration => ComponentType::String, None);
+
+#[cfg(feature = "decimal")]
+impl_partial_component!(rust_decimal::Decimal => ComponentType::String, None);
+#[cfg(feature = "uuid")]
+impl_partial_component!(uuid::Uuid => ComponentType::String, Some(ComponentFormat::Uuid));
+#[cfg(feature = "url")]
+impl_partial_component!(url::Url => ComponentType::String, Some(ComponentFormat::Uri));
+
+// `Bytes` is serialized as a sequence of bytes the same way as `Vec<u8>`.
+#[cfg(feature = "bytes")]
+impl PartialComponent for bytes::Bytes {
+    fn partial_component() -> Component {
+        <[u8]>::partial_component()
+    }
+}
+
+// Free-form component without type accepts any JSON value.
+#[cfg(feature = "json_value")]
+impl PartialComponent for serde_json::Value {
+    fn partial_component() -> Component {
+        FreeFormBuilder::new().into()
+    }
+}
+
+/// Used by the derive macros to resolve [`Component`] of type `T`. Do not use directly.
+#[doc(hidden)]
+pub struct ComponentOf<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> ComponentOf<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for ComponentOf<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resolves [`Component`] with [`PartialComponent`] implementation of the type. Takes
+/// precedence over [`ResolveComponentRef`] and [`ResolveRef`] when called with
+/// `(&&&ComponentOf::<T>::new()).resolve(...)`.
+#[doc(hidden)]
+pub trait ResolvePartialComponent {
+    fn resolve(&self) -> Component;
+}
+
+impl<T: PartialComponent + ?Sized> ResolvePartialComponent for &&ComponentOf<T> {
+    fn resolve(&self) -> Component {
+        T::partial_component()
+    }
+}
+
+/// Resolves [`Component`] as [`Ref`] to the name of the component returned by
+/// [`Component::component_name`][component_name] of the type. Name resolved with
+/// [`type_component_name`] is used when the type does not define a name. Takes precedence
+/// over [`ResolveRef`].
+///
+/// [component_name]: crate::Component::component_name
+#[doc(hidden)]
+pub trait ResolveComponentRef {
+    fn resolve(&self) -> Component;
+}
+
+impl<T: crate::Component> ResolveComponentRef for &ComponentOf<T> {
+    fn resolve(&self) -> Component {
+        component_ref::<T>()
+    }
+}
+
+/// Resolves [`Component`] as [`Ref`] to the name resolved with [`type_component_name`]. Used
+/// when type implements neither [`PartialComponent`] nor [`Component`][component] e.g. remote
+/// types.
+///
+/// [component]: crate::Component
+#[doc(hidden)]
+pub trait ResolveRef {
+    fn resolve(&self) -> Component;
+}
+
+impl<T: ?Sized> ResolveRef for ComponentOf<T> {
+    fn resolve(&self) -> Component {
+        Ref::from_component_name(type_component_name::<T>()).into()
+    }
+}
+
+/// Resolves inlined [`Component`] with [`PartialComponent::inline_partial_component`] of the type.
+/// Takes precedence over [`ResolveInlineComponent`] when called with
+/// `(&&ComponentOf::<T>::new()).resolve_inline()`.
+#[doc(hidden)]
+pub trait ResolveInlinePartialComponent {
+    fn resolve_inline(&self) -> Component;
+}
+
+impl<T: PartialComponent + ?Sized> ResolveInlinePartialComponent for &ComponentOf<T> {
+    fn resolve_inline(&self) -> Component {
+        T::inline_partial_component()
+    }
+}
+
+/// Resolves inlined [`Component`] with [`Component::component`][component] of the type.
+///
+/// [component]: crate::Component::component
+#[doc(hidden)]
+pub trait ResolveInlineComponent {
+    fn resolve_inline(&self) -> Component;
+}
+
+impl<T: crate::Component> ResolveInlineComponent for ComponentOf<T> {
+    fn resolve_inline(&self) -> Component {
+        T::component()
+    }
+}
+
+/// Serialize the [`Default`] value of type `T` to resolve the defaults of its fields. Returns `None`
+/// if the value cannot be serialized e.g. when it contains a map with non string keys in which case
+/// the fields are documented without defaults.
+#[cfg(feature = "serde_json")]
+#[doc(hidden)]
+pub fn serialize_default<T: Default + serde::Serialize>() -> Option<Value> {
+    serde_json::to_value(T::default()).ok()
+}
+
+/// Create [`Ref`] to the component of type `T`. The name is returned by
+/// [`Component::component_name`][component_name] or resolved with [`type_component_name`]
+/// when the type does not define a name.
+///
+/// [component_name]: crate::Component::component_name
+#[doc(hidden)]
+pub fn component_ref<T: crate::Component>() -> Component {
+    Ref::from_component_name(
+        T::component_name().unwrap_or_else(|| type_component_name::<T>().into()),
+    )
+    .into()
+}
+
+/// Resolve name of the component from the [`module_path!`] of the type with
+/// **namespaced_components** feature. The crate name is excluded e.g. `Pet` in
+/// `my_crate::api::v2` module is named `api.v2.Pet`.
+#[doc(hidden)]
+pub fn namespaced_component_name(module_path: &str, name: &str) -> String {
+    module_path
+        .split("::")
+        .skip(1)
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Resolve name of the component of a type which does not define
+/// [`Component::component_name`][component_name] from the [`std::any::type_name`] of the type.
+/// The name is resolved the same way as the names of the derived components thus references
+/// to the type match the registered component regardless of how the type is written. Generic
+/// arguments are excluded.
+///
+/// By default the name is the name of the type e.g. `Pet`. With **namespaced_components**
+/// feature the name is resolved with [`namespaced_component_name`] e.g. `api.v2.Pet`.
+///
+/// [component_name]: crate::Component::component_name
+#[doc(hidden)]
+pub fn type_component_name<T: ?Sized>() -> String {
+    let type_name = std::any::type_name::<T>();
+    let path = type_name
+        .split_once('<')
+        .map_or(type_name, |(path, _)| path);
+    let (module_path, name) = path.rsplit_once("::").unwrap_or(("", path));
+
+    if cfg!(feature = "namespaced_components") {
+        namespaced_component_name(module_path, name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Set the attributes of [`FieldAttributes`] which are defined to the builder. Undefined
+/// attributes do not override the values of the resolved component.
+macro_rules! set_attributes {
+    ( $attributes:expr, $builder:expr, $( $attribute:ident ),* ) => {{
+        let builder = $builder;
+        $(
+            let builder = match $attributes.$attribute {
+                Some(value) => builder.$attribute(Some(value)),
+                None => builder,
+            };
+        )*
+        builder
+    }};
+}
+
+/// Attributes of a field which are applied to the [`Component`] resolved from the type of the
+/// field with [`FieldAttributes::apply`]. Attributes are applied to every kind of component:
+///
+/// * [`Property`][property] and [`Object`][object] take the attributes as is.
+/// * [`Array`] takes the attributes except `format` and `xml` which are applied to the array items.
+///   `wrapper_xml` defined with `xml(wrapped)` is applied to the array.
+/// * [`OneOf`][one_of] takes the attributes except `format`.
+/// * [`Ref`] is wrapped to single item [`OneOf`][one_of] which takes the attributes since
+///   properties beside `$ref` are ignored.
+///
+/// `Binary` and `Byte` formats document the field as `string` of the format regardless of the
+/// component. `format` of other components than properties and `wrapper_xml` of other
+/// components than arrays are ignored.
+///
+/// [property]: crate::openapi::schema::Property
+/// [object]: crate::openapi::schema::Object
+/// [one_of]: crate::openapi::schema::OneOf
+#[doc(hidden)]
+#[derive(Default)]
+pub struct FieldAttributes {
+    title: Option<String>,
+    description: Option<String>,
+    deprecated: Option<Deprecated>,
+    #[cfg(feature = "serde_json")]
+    default: Option<Value>,
+    #[cfg(not(feature = "serde_json"))]
+    default: Option<String>,
+    #[cfg(feature = "serde_json")]
+    example: Option<Value>,
+    #[cfg(not(feature = "serde_json"))]
+    example: Option<String>,
+    format: Option<ComponentFormat>,
+    write_only: Option<bool>,
+    read_only: Option<bool>,
+    xml: Option<Xml>,
+    wrapper_xml: Option<Xml>,
+}
+
+impl FieldAttributes {
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    pub fn title<I: Into<String>>(mut self, title: Option<I>) -> Self {
+        self.title = title.map(Into::into);
+        self
+    }
+
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        self.description = description.map(Into::into);
+        self
+    }
+
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        self.deprecated = deprecated;
+        self
+    }
+
+    #[cfg(feature = "serde_json")]
+    pub fn default(mut self, default: Option<Value>) -> Self {
+        self.default = default;
+        self
+    }
+
+    #[cfg(not(feature = "serde_json"))]
+    pub fn default<I: Into<String>>(mut self, default: Option<I>) -> Self {
+        self.default = default.map(Into::into);
+        self
+    }
+
+    #[cfg(feature = "serde_json")]
+    pub fn example(mut self, example: Option<Value>) -> Self {
+        self.example = example;
+        self
+    }
+
+    #[cfg(not(feature = "serde_json"))]
+    pub fn example<I: Into<String>>(mut self, example: Option<I>) -> Self {
+        self.example = example.map(Into::into);
+        self
+    }
+
+    pub fn format(mut self, format: Option<ComponentFormat>) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn write_only(mut self, write_only: Option<bool>) -> Self {
+        self.write_only = write_only;
+        self
+    }
+
+    pub fn read_only(mut self, read_only: Option<bool>) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn xml(mut self, xml: Option<Xml>) -> Self {
+        self.xml = xml;
+        self
+    }
+
+    pub fn wrapper_xml(mut self, wrapper_xml: Option<Xml>) -> Self {
+        self.wrapper_xml = wrapper_xml;
+        self
+    }
+
+    /// Check whether any of the attributes other than `format` and xml is defined. These are the
+    /// attributes which require wrapping [`Ref`] to [`OneOf`][one_of].
+    ///
+    /// [one_of]: crate::openapi::schema::OneOf
+    fn has_schema_attributes(&self) -> bool {
+        self.title.is_some()
+            || self.description.is_some()
+            || self.deprecated.is_some()
+            || self.default.is_some()
+            || self.example.is_some()
+            || self.write_only.is_some()
+            || self.read_only.is_some()
+    }
+
+    /// Apply the attributes to the `component` resolved from the type of the field.
+    pub fn apply(self, component: Component) -> Component {
+        if matches!(
+            self.format,
+            Some(ComponentFormat::Binary) | Some(ComponentFormat::Byte)
+        ) {
+            return self
+                .apply_property(PropertyBuilder::new().component_type(ComponentType::String));
+        }
+
+        match component {
+            Component::Property(property) => self.apply_property(property.into()),
+            Component::Object(object) => set_attributes!(
+                self,
+                ObjectBuilder::from(object),
+                title,
+                description,
+                deprecated,
+                default,
+                example,
+                write_only,
+                read_only,
+                xml
+            )
+            .into(),
+            Component::Array(mut array) => {
+                let items = std::mem::take(&mut *array.items);
+                *array.items = FieldAttributes {
+                    format: self.format.clone(),
+                    xml: self.xml.clone(),
+                    ..Default::default()
+                }
+                .apply(items);
+
+                let array = set_attributes!(
+                    self,
+                    array.to_builder(),
+                    title,
+                    description,
+                    deprecated,
+                    default,
+                    example,
+                    write_only,
+                    read_only
+                );
+                match self.wrapper_xml {
+                    Some(wrapper_xml) => array.xml(Some(wrapper_xml)),
+                    None => array,
+                }
+                .into()
+            }
+            Component::OneOf(one_of) => self.apply_one_of(one_of.into()),
+            Component::FreeForm(free_form) => set_attributes!(
+                self,
+                FreeFormBuilder::from(free_form),
+                title,
+                description,
+                deprecated,
+                default,
+                example,
+                write_only,
+                read_only,
+                xml
+            )
+            .into(),
+            Component::Ref(reference) if self.has_schema_attributes() || self.xml.is_some() => {
+                self.apply_one_of(OneOfBuilder::new().item(reference))
+            }
+            component => component,
+        }
+    }
+
+    fn apply_property(self, property: PropertyBuilder) -> Component {
+        set_attributes!(
+            self,
+            property,
+            format,
+            title,
+            description,
+            deprecated,
+            default,
+            example,
+            write_only,
+            read_only,
+            xml
+        )
+        .into()
+    }
+
+    fn apply_one_of(self, one_of: OneOfBuilder) -> Component {
+        set_attributes!(
+            self,
+            one_of,
+            title,
+            description,
+            deprecated,
+            default,
+            example,
+            write_only,
+            read_only,
+            xml
+        )
+        .into()
+    }
+}
+
+/// Resolve default content type of request or response body with the given schema. Primitive
+/// types and arrays of them are `text/plain` and other types including free-form values are
+/// `application/json`.
+#[doc(hidden)]
+pub fn default_content_type(component: &Component) -> &'static str {
+    match component {
+        Component::Property(property)
+            if !matches!(property.component_type, ComponentType::Object) =>
+        {
+            "text/plain"
+        }
+        Component::Array(array) => default_content_type(&array.items),
+        _ => "application/json",
+    }
+}
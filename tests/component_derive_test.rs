@@ -1,7 +1,8 @@
 #![cfg(feature = "serde_json")]
 use std::{borrow::Cow, cell::RefCell, collections::HashMap, vec};
 
-use chrono::{Date, DateTime, Duration, Utc};
+#[cfg(any(feature = "chrono_types", feature = "chrono_types_with_format"))]
+use chrono::{Date, DateTime, Duration, NaiveDate, NaiveDateTime, Utc, Weekday};
 use serde_json::Value;
 use utoipa::{Component, OpenApi};
 
@@ -155,7 +156,11 @@ fn derive_struct_with_custom_properties_success() {
 
 #[test]
 fn derive_struct_with_optional_properties_success() {
-    struct Book;
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Book {
+        name: String,
+    }
     let owner = api_doc! {
         struct Owner {
             #[component(default = 1)]
@@ -169,16 +174,19 @@ fn derive_struct_with_optional_properties_success() {
     assert_value! {owner=>
         "type" = r#""object""#, "Owner type"
         "properties.id.type" = r#""integer""#, "Owner id type"
-        "properties.id.format" = r#""int64""#, "Owner id format"
+        "properties.id.format" = r#"null"#, "Owner id format"
         "properties.id.default" = r#"1"#, "Owner id default"
         "properties.enabled.type" = r#""boolean""#, "Owner enabled"
         "properties.books.type" = r#""array""#, "Owner books"
-        "properties.books.items.$ref" = r###""#/components/schemas/Book""###, "Owner books items ref"
         "properties.metadata.type" = r#""object""#, "Owner metadata"
     };
     assert_value! {owner=>
         "required" = Value::Array(vec![Value::String("id".to_string())]), "Owner required"
     }
+
+    assert_value! {owner=>
+        "properties.books.items.$ref" = r###""#/components/schemas/Book""###, "Owner books items ref"
+    };
 }
 
 #[test]
@@ -187,7 +195,7 @@ fn derive_struct_with_comments_success() {
         /// This is user account dto object
         ///
         /// Detailed documentation here
-        /// Only first line is added to the description so far
+        /// spanning multiple lines
         struct Account {
             /// Database autogenerated id
             id: i64,
@@ -198,7 +206,7 @@ fn derive_struct_with_comments_success() {
     };
 
     assert_value! {account=>
-        "description" = r#""This is user account dto object""#, "Account description"
+        "description" = r#""This is user account dto object\n\nDetailed documentation here\nspanning multiple lines""#, "Account description"
         "properties.id.description" = r#""Database autogenerated id""#, "Account id description"
         "properties.username.description" = r#""Users username""#, "Account username description"
         "properties.role_ids.type" = r#""array""#, "Account role_ids type"
@@ -208,16 +216,176 @@ fn derive_struct_with_comments_success() {
     }
 }
 
+#[test]
+fn derive_struct_with_container_default() {
+    #[derive(serde::Serialize, serde::Deserialize, Component)]
+    #[serde(default)]
+    #[component(default)]
+    #[allow(unused)]
+    struct Settings {
+        page_size: u32,
+        sort: String,
+        #[component(default = 1)]
+        page: u32,
+        filter: Option<String>,
+        cursor: Option<String>,
+        tags: Vec<String>,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                page_size: 20,
+                sort: "name".to_string(),
+                page: 0,
+                filter: Some("all".to_string()),
+                cursor: None,
+                tags: vec!["new".to_string()],
+            }
+        }
+    }
+
+    let settings = serde_json::to_value(Settings::component()).unwrap();
+
+    assert_value! {settings=>
+        "properties.page_size.default" = r#"20"#, "Settings page_size default"
+        "properties.sort.default" = r#""name""#, "Settings sort default"
+        "properties.page.default" = r#"1"#, "Settings page default"
+        "properties.filter.default" = r#""all""#, "Settings filter default"
+        "properties.tags.default" = r#"["new"]"#, "Settings tags default"
+        "required" = r#"null"#, "Settings required"
+    }
+    assert!(
+        settings["properties"]["cursor"].get("default").is_none(),
+        "Settings cursor should not have default"
+    );
+}
+
+
+#[test]
+fn derive_struct_with_unserializable_container_default() {
+    #[derive(serde::Serialize, Component)]
+    #[component(default)]
+    #[allow(unused)]
+    struct Grid {
+        name: String,
+        #[component(default = 2)]
+        size: u32,
+        cells: HashMap<(u8, u8), String>,
+    }
+
+    impl Default for Grid {
+        fn default() -> Self {
+            Self {
+                name: "grid".to_string(),
+                size: 1,
+                cells: HashMap::from([((0, 0), "start".to_string())]),
+            }
+        }
+    }
+
+    let grid = serde_json::to_value(Grid::component()).unwrap();
+
+    assert_value! {grid=>
+        "properties.name.type" = r#""string""#, "Grid name type"
+        "properties.size.default" = r#"2"#, "Grid size default"
+        "properties.cells.type" = r#""object""#, "Grid cells type"
+    }
+    assert!(
+        grid["properties"]["name"].get("default").is_none(),
+        "Grid name should not have default"
+    );
+}
+#[test]
+fn derive_struct_with_serde_default_fields() {
+    fn default_kind() -> String {
+        "dog".to_string()
+    }
+
+    #[derive(serde::Deserialize, Component)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+        #[serde(default)]
+        age: u8,
+        #[serde(default = "default_kind")]
+        kind: String,
+    }
+
+    let pet = serde_json::to_value(Pet::component()).unwrap();
+
+    assert_value! {pet=>
+        "required" = r#"["name"]"#, "Pet required"
+    }
+}
+
+#[test]
+fn derive_struct_with_markdown_comments() {
+    let account = api_doc! {
+        /// Account of a user.
+        ///
+        /// Supported roles:
+        /// * admin
+        ///   with full access
+        /// * user
+        ///
+        /// ```text
+        ///     indented code
+        /// ```
+        struct Account {
+            /// Users username
+            ///
+            /// Must be unique.
+            username: String,
+        }
+    };
+
+    assert_value! {account=>
+        "description" = r#""Account of a user.\n\nSupported roles:\n* admin\n  with full access\n* user\n\n```text\n    indented code\n```""#, "Account description"
+        "properties.username.description" = r#""Users username\n\nMust be unique.""#, "Account username description"
+    }
+}
+
+#[test]
+fn derive_component_with_description_attribute() {
+    let account = api_doc! {
+        /// Overridden by description attribute
+        #[component(description = "Account of a user")]
+        struct Account {
+            /// Overridden by description attribute
+            #[component(description = include_str!("../README.md"))]
+            username: String,
+        }
+    };
+
+    assert_value! {account=>
+        "description" = r#""Account of a user""#, "Account description"
+    }
+    assert_value! {account=>
+        "properties.username.description" = Value::String(include_str!("../README.md").to_string()), "Account username description"
+    }
+
+    let status = api_doc! {
+        #[component(description = "Status of the account")]
+        enum AccountStatus {
+            Enabled,
+            Disabled,
+        }
+    };
+
+    assert_value! {status=>
+        "description" = r#""Status of the account""#, "AccountStatus description"
+    }
+}
+
 #[test]
 fn derive_enum_with_comments_success() {
     let account = api_doc! {
         /// This is user account status enum
         ///
         /// Detailed documentation here
-        /// Only first line is added to the description so far
         enum AccountStatus {
-            /// When user is valid to login, these enum variant level docs are omitted!!!!!
-            /// Since the OpenAPI spec does not have a place to put such infomation.
+            /// When user is valid to login
             Enabled,
             /// Login failed too many times
             Locked,
@@ -226,7 +394,155 @@ fn derive_enum_with_comments_success() {
     };
 
     assert_value! {account=>
-        "description" = r#""This is user account status enum""#, "AccountStatus description"
+        "description" = r#""This is user account status enum\n\nDetailed documentation here""#, "AccountStatus description"
+        "x-enum-varnames" = r#"["Enabled","Locked","Disabled"]"#, "AccountStatus variant names"
+        "x-enum-descriptions" = r#"["When user is valid to login","Login failed too many times",""]"#, "AccountStatus variant descriptions"
+    }
+}
+
+#[test]
+fn derive_simple_enum_as_one_of_with_variant_descriptions() {
+    #[allow(deprecated)]
+    let status = api_doc! {
+        /// Status of the account
+        #[deprecated]
+        #[component(one_of)]
+        enum AccountStatus {
+            /// When user is valid to login
+            Enabled,
+            #[deprecated]
+            Locked,
+        }
+    };
+
+    assert_value! {status=>
+        "description" = r#""Status of the account""#, "AccountStatus description"
+        "deprecated" = r#"true"#, "AccountStatus deprecated"
+        "enum" = r#"null"#, "AccountStatus enum values"
+        "x-enum-descriptions" = r#"null"#, "AccountStatus variant descriptions"
+    }
+
+    let variants = status.get("oneOf").unwrap();
+    assert_value! {variants[0]=>
+        "enum" = r#"["Enabled"]"#, "Enabled enum values"
+        "type" = r#""string""#, "Enabled type"
+        "description" = r#""When user is valid to login""#, "Enabled description"
+        "deprecated" = r#"null"#, "Enabled deprecated"
+    }
+    assert_value! {variants[1]=>
+        "enum" = r#"["Locked"]"#, "Locked enum values"
+        "description" = r#"null"#, "Locked description"
+        "deprecated" = r#"true"#, "Locked deprecated"
+    }
+}
+
+#[test]
+fn derive_integer_enum_with_repr() {
+    #[derive(serde_repr::Serialize_repr, Component)]
+    #[component(repr)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Level {
+        Low = 1,
+        High = 5,
+        Critical,
+    }
+
+    let level = serde_json::to_value(Level::component()).unwrap();
+
+    assert_value! {level=>
+        "type" = r#""integer""#, "Level type"
+        "enum" = r#"[1,5,6]"#, "Level enum values"
+    }
+    assert_eq!(serde_json::to_string(&Level::High).unwrap(), "5");
+}
+
+#[test]
+fn derive_integer_enum_with_serialize_repr_derive() {
+    #[derive(Component)]
+    #[derive(serde_repr::Serialize_repr)]
+    #[repr(i16)]
+    #[allow(unused)]
+    enum Level {
+        Low = -1,
+        High = 1,
+    }
+
+    let level = serde_json::to_value(Level::component()).unwrap();
+
+    assert_value! {level=>
+        "type" = r#""integer""#, "Level type"
+        "enum" = r#"[-1,1]"#, "Level enum values"
+    }
+}
+
+#[test]
+fn derive_enum_with_repr_without_serde_repr_as_string_enum() {
+    #[derive(serde::Serialize, Component)]
+    #[repr(u8)]
+    #[allow(unused)]
+    enum Level {
+        Low = 1,
+        High = 5,
+    }
+
+    let level = serde_json::to_value(Level::component()).unwrap();
+
+    assert_value! {level=>
+        "type" = r#""string""#, "Level type"
+        "enum" = r#"["Low","High"]"#, "Level enum values"
+    }
+    assert_eq!(serde_json::to_string(&Level::High).unwrap(), r#""High""#);
+}
+
+#[test]
+fn derive_enum_with_variant_values() {
+    let status = api_doc! {
+        enum Status {
+            #[component(value = 200)]
+            Ok,
+            #[component(value = 404)]
+            NotFound,
+        }
+    };
+
+    assert_value! {status=>
+        "type" = r#""integer""#, "Status type"
+        "enum" = r#"[200,404]"#, "Status enum values"
+    }
+
+    let mixed = api_doc! {
+        enum Mixed {
+            #[component(value = 1)]
+            One,
+            #[component(value = "two")]
+            Two,
+            Three,
+        }
+    };
+
+    assert_value! {mixed=>
+        "type" = r#"null"#, "Mixed type"
+        "oneOf.[0].type" = r#""integer""#, "Mixed One type"
+        "oneOf.[0].enum" = r#"[1]"#, "Mixed One value"
+        "oneOf.[1].type" = r#""string""#, "Mixed Two type"
+        "oneOf.[1].enum" = r#"["two"]"#, "Mixed Two value"
+        "oneOf.[2].enum" = r#"["Three"]"#, "Mixed Three value"
+    }
+}
+
+#[test]
+fn derive_simple_enum_without_variant_comments() {
+    let status = api_doc! {
+        enum AccountStatus {
+            Enabled,
+            Locked,
+        }
+    };
+
+    assert_value! {status=>
+        "x-enum-varnames" = r#"null"#, "AccountStatus variant names"
+        "x-enum-descriptions" = r#"null"#, "AccountStatus variant descriptions"
     }
 }
 
@@ -255,7 +571,8 @@ fn derive_struct_unnamed_fields_tuple_with_same_type_success() {
         "type" = r#""array""#, "Point type"
         "items.type" = r#""number""#, "Point items type"
         "items.format" = r#""float""#, "Point items format"
-        "items.description" = r#""Contains x and y coordinates""#, "Point items description"
+        "description" = r#""Contains x and y coordinates\n\nCoordinates are used to pinpoint location on a map""#, "Point description"
+        "items.description" = r#"null"#, "Point items description"
         "maxItems" = r#"2"#, "Wrapper max items"
         "minItems" = r#"2"#, "Wrapper min items"
     }
@@ -269,8 +586,10 @@ fn derive_struct_unnamed_fields_tuple_with_different_types_success() {
 
     assert_value! {point=>
         "type" = r#""array""#, "Point type"
-        "items.type" = r#""object""#, "Point items type"
-        "items.format" = r#"null"#, "Point items format"
+        "items.type" = r#"null"#, "Point items type"
+        "items.oneOf" = r#"[{"format":"float","type":"number"},{"type":"string"}]"#, "Point items one of"
+        "minItems" = r#"2"#, "Point min items"
+        "maxItems" = r#"2"#, "Point max items"
     }
 }
 
@@ -306,8 +625,9 @@ fn derive_struct_unnamed_field_with_multiple_nested_generic_type_success() {
 
     assert_value! {point=>
         "type" = r#""array""#, "Wrapper type"
-        "items.type" = r#""object""#, "Wrapper items type"
-        "items.format" = r#"null"#, "Wrapper items format"
+        "items.oneOf" = r#"[{"items":{"format":"int32","type":"integer"},"type":"array"},{"type":"string"}]"#, "Wrapper items one of"
+        "minItems" = r#"2"#, "Wrapper min items"
+        "maxItems" = r#"2"#, "Wrapper max items"
     }
 }
 
@@ -360,6 +680,58 @@ fn derive_struct_with_example() {
     }
 }
 
+#[test]
+fn derive_struct_with_example_function() {
+    #[derive(serde::Serialize, Component)]
+    #[component(example = Pet::example)]
+    struct Pet {
+        name: String,
+        age: i32,
+    }
+
+    impl Pet {
+        fn example() -> Self {
+            Self {
+                name: "bob the cat".to_string(),
+                age: 8,
+            }
+        }
+    }
+
+    let pet = serde_json::to_value(Pet::component()).unwrap();
+
+    assert_value! {pet=>
+        "example.name" = r#""bob the cat""#, "Pet example name"
+        "example.age" = r#"8"#, "Pet example age"
+    }
+}
+
+#[test]
+fn derive_struct_with_self_example_function() {
+    #[derive(serde::Serialize, Component)]
+    #[component(example = Self::example)]
+    struct Pet {
+        name: String,
+        age: i32,
+    }
+
+    impl Pet {
+        fn example() -> Self {
+            Self {
+                name: "bob the cat".to_string(),
+                age: 8,
+            }
+        }
+    }
+
+    let pet = serde_json::to_value(Pet::component()).unwrap();
+
+    assert_value! {pet=>
+        "example.name" = r#""bob the cat""#, "Pet example name"
+        "example.age" = r#"8"#, "Pet example age"
+    }
+}
+
 #[test]
 fn derive_struct_with_deprecated() {
     #[allow(deprecated)]
@@ -406,10 +778,10 @@ fn derive_unnamed_struct_example_json_array_success() {
 
     assert_value! {pet_age=>
         "type" = r#""array""#, "PetAge type"
-        "items.example" = r#""0""#, "PetAge example"
-        "items.default" = r#"0"#, "PetAge default"
+        "example" = r#""0""#, "PetAge example"
+        "default" = r#"0"#, "PetAge default"
         "items.type" = r#""integer""#, "PetAge default"
-        "items.format" = r#""int64""#, "PetAge default"
+        "items.format" = r#"null"#, "PetAge default"
         "maxItems" = r#"2"#, "PetAge max items"
         "minItems" = r#"2"#, "PetAge min items"
     }
@@ -445,9 +817,15 @@ fn derive_struct_with_generics() {
         }
     };
 
+    #[cfg(not(feature = "namespaced_components"))]
     assert_value! {status=>
         "properties.t.$ref" = r###""#/components/schemas/Type""###, "Status t field"
     };
+
+    #[cfg(feature = "namespaced_components")]
+    assert_value! {status=>
+        "properties.t.$ref" = r###""#/components/schemas/derive_struct_with_generics.Type""###, "Status t field"
+    };
 }
 
 #[test]
@@ -482,6 +860,7 @@ fn derive_struct_with_cow() {
 
 #[test]
 fn derive_with_box_and_refcell() {
+    #[derive(Component)]
     #[allow(unused)]
     struct Foo {
         name: &'static str,
@@ -496,11 +875,14 @@ fn derive_with_box_and_refcell() {
 
     common::assert_json_array_len(greeting.get("required").unwrap(), 2);
     assert_value! {greeting=>
-        "properties.foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting foo field"
-        "properties.ref_cell_foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting ref_cell_foo field"
         "required.[0]" = r###""foo""###, "Greeting required 0"
         "required.[1]" = r###""ref_cell_foo""###, "Greeting required 1"
     };
+
+    assert_value! {greeting=>
+        "properties.foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting foo field"
+        "properties.ref_cell_foo.$ref" = r###""#/components/schemas/Foo""###, "Greeting ref_cell_foo field"
+    };
 }
 
 #[test]
@@ -526,8 +908,17 @@ fn derive_complex_enum_with_named_and_unnamed_fields() {
         "oneOf.[1].properties.NamedFields.properties.id.type" = r###""string""###, "Complex enum named fields id type"
         "oneOf.[1].properties.NamedFields.properties.names.type" = r###""array""###, "Complex enum named fields names type"
         "oneOf.[2].type" = r###""object""###, "Complex enum unnamed fields type"
-        "oneOf.[2].properties.UnnamedFields.$ref" = r###""#/components/schemas/Foo""###, "Complex enum unnamed fields type"
     }
+
+    #[cfg(not(feature = "namespaced_components"))]
+    assert_value! {complex_enum=>
+        "oneOf.[2].properties.UnnamedFields.$ref" = r###""#/components/schemas/Foo""###, "Complex enum unnamed fields type"
+    };
+
+    #[cfg(feature = "namespaced_components")]
+    assert_value! {complex_enum=>
+        "oneOf.[2].properties.UnnamedFields.$ref" = r###""#/components/schemas/derive_complex_enum_with_named_and_unnamed_fields.Foo""###, "Complex enum unnamed fields type"
+    };
 }
 
 #[test]
@@ -613,6 +1004,11 @@ fn derive_component_with_chrono_types_with_chrono_with_format_feature() {
             datetime: DateTime<Utc>,
             date: Date<Utc>,
             duration: Duration,
+            naive_datetime: NaiveDateTime,
+            naive_date: NaiveDate,
+            naive_time: chrono::NaiveTime,
+            offset_datetime: DateTime<chrono::FixedOffset>,
+            weekday: Weekday,
         }
     };
 
@@ -622,7 +1018,18 @@ fn derive_component_with_chrono_types_with_chrono_with_format_feature() {
         "properties.date.type" = r#""string""#, "Post date type"
         "properties.date.format" = r#""date""#, "Post date format"
         "properties.duration.type" = r#""string""#, "Post duration type"
-        "properties.duration.format" = r#"null"#, "Post duration format"
+        "properties.duration.format" = r#""duration""#, "Post duration format"
+        "properties.naive_datetime.type" = r#""string""#, "Post naive_datetime type"
+        "properties.naive_datetime.format" = r#""date-time""#, "Post naive_datetime format"
+        "properties.naive_date.type" = r#""string""#, "Post naive_date type"
+        "properties.naive_date.format" = r#""date""#, "Post naive_date format"
+        "properties.naive_time.type" = r#""string""#, "Post naive_time type"
+        "properties.naive_time.format" = r#""time""#, "Post naive_time format"
+        "properties.offset_datetime.type" = r#""string""#, "Post offset_datetime type"
+        "properties.offset_datetime.format" = r#""date-time""#, "Post offset_datetime format"
+        "properties.weekday.type" = r#""string""#, "Post weekday type"
+        "properties.weekday.format" = r#"null"#, "Post weekday format"
+        "properties.weekday.enum" = r#"["Mon","Tue","Wed","Thu","Fri","Sat","Sun"]"#, "Post weekday enum"
         "properties.id.type" = r#""integer""#, "Post id type"
         "properties.id.format" = r#""int32""#, "Post id format"
         "properties.value.type" = r#""string""#, "Post value type"
@@ -640,21 +1047,69 @@ fn derive_component_with_chrono_types_with_chrono_feature() {
             datetime: DateTime<Utc>,
             date: Date<Utc>,
             duration: Duration,
+            naive_datetime: NaiveDateTime,
+            naive_date: NaiveDate,
+            naive_time: chrono::NaiveTime,
+            offset_datetime: DateTime<chrono::FixedOffset>,
+            weekday: Weekday,
         }
     };
 
     assert_value! {post=>
         "properties.datetime.type" = r#""string""#, "Post datetime type"
-        "properties.datetime.format" = r#"null"#, "Post datetime format"
         "properties.date.type" = r#""string""#, "Post date type"
-        "properties.date.format" = r#"null"#, "Post date format"
         "properties.duration.type" = r#""string""#, "Post duration type"
-        "properties.duration.format" = r#"null"#, "Post duration format"
+        "properties.naive_datetime.type" = r#""string""#, "Post naive_datetime type"
+        "properties.naive_date.type" = r#""string""#, "Post naive_date type"
+        "properties.naive_time.type" = r#""string""#, "Post naive_time type"
+        "properties.offset_datetime.type" = r#""string""#, "Post offset_datetime type"
+        "properties.weekday.type" = r#""string""#, "Post weekday type"
+        "properties.weekday.enum" = r#"["Mon","Tue","Wed","Thu","Fri","Sat","Sun"]"#, "Post weekday enum"
         "properties.id.type" = r#""integer""#, "Post id type"
         "properties.id.format" = r#""int32""#, "Post id format"
         "properties.value.type" = r#""string""#, "Post value type"
         "properties.value.format" = r#"null"#, "Post value format"
     }
+
+    // formats are only documented with `chrono_types_with_format` feature
+    #[cfg(not(feature = "chrono_types_with_format"))]
+    assert_value! {post=>
+        "properties.datetime.format" = r#"null"#, "Post datetime format"
+        "properties.date.format" = r#"null"#, "Post date format"
+        "properties.duration.format" = r#"null"#, "Post duration format"
+        "properties.naive_datetime.format" = r#"null"#, "Post naive_datetime format"
+        "properties.naive_time.format" = r#"null"#, "Post naive_time format"
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn derive_component_with_time_types_with_time_feature() {
+    let post = api_doc! {
+        struct Post {
+            id: i32,
+            created: time::OffsetDateTime,
+            updated: Option<time::PrimitiveDateTime>,
+            date: time::Date,
+            time: time::Time,
+            duration: time::Duration,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.created.type" = r#""string""#, "Post created type"
+        "properties.created.format" = r#""date-time""#, "Post created format"
+        "properties.updated.type" = r#""string""#, "Post updated type"
+        "properties.updated.format" = r#""date-time""#, "Post updated format"
+        "properties.date.type" = r#""string""#, "Post date type"
+        "properties.date.format" = r#""date""#, "Post date format"
+        "properties.time.type" = r#""string""#, "Post time type"
+        "properties.time.format" = r#""time""#, "Post time format"
+        "properties.duration.type" = r#""string""#, "Post duration type"
+        "properties.duration.format" = r#"null"#, "Post duration format"
+        "properties.id.type" = r#""integer""#, "Post id type"
+        "properties.id.format" = r#""int32""#, "Post id format"
+    }
 }
 
 #[test]
@@ -675,6 +1130,41 @@ fn derive_struct_component_field_type_override() {
     }
 }
 
+#[test]
+fn derive_struct_component_field_type_override_with_any_type() {
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[allow(unused)]
+    struct Json<T>(T);
+
+    let post = api_doc! {
+        struct Post {
+            #[component(value_type = Pet)]
+            pet: Json<Pet>,
+            #[component(value_type = Vec<Pet>)]
+            pets: Json<Vec<Pet>>,
+            #[component(value_type = Option<String>)]
+            comment: Json<Option<String>>,
+            #[component(value_type = Object)]
+            metadata: Json<HashMap<String, String>>,
+        }
+    };
+
+    assert_value! {post=>
+        "properties.pet.$ref" = r##""#/components/schemas/Pet""##, "Post pet ref"
+        "properties.pets.type" = r#""array""#, "Post pets type"
+        "properties.pets.items.$ref" = r##""#/components/schemas/Pet""##, "Post pets items ref"
+        "properties.comment.type" = r#""string""#, "Post comment type"
+        "properties.metadata.type" = r#""object""#, "Post metadata type"
+        "properties.metadata.properties" = r#"null"#, "Post metadata properties"
+        "required" = r#"["pet","pets","metadata"]"#, "Post required fields"
+    }
+}
+
 #[test]
 fn derive_struct_component_field_type_override_with_format() {
     let post = api_doc! {
@@ -693,6 +1183,24 @@ fn derive_struct_component_field_type_override_with_format() {
     }
 }
 
+#[test]
+fn derive_struct_component_field_with_custom_format() {
+    let account = api_doc! {
+        struct Account {
+            #[component(format = "iban")]
+            iban: String,
+            #[component(format = ComponentFormat::Password)]
+            password: String,
+        }
+    };
+
+    assert_value! {account=>
+        "properties.iban.type" = r#""string""#, "Account iban type"
+        "properties.iban.format" = r#""iban""#, "Account iban format"
+        "properties.password.format" = r#""password""#, "Account password format"
+    }
+}
+
 #[test]
 fn derive_struct_component_field_type_override_with_format_with_vec() {
     let post = api_doc! {
@@ -777,3 +1285,720 @@ fn derive_struct_with_rust_decimal_with_type_override() {
         "properties.rating.format" = r#""float""#, "Post rating format"
     }
 }
+
+mod pet_models {
+    #[allow(dead_code)]
+    #[derive(utoipa::Component)]
+    pub struct Pet {
+        pub name: std::string::String,
+    }
+}
+
+#[test]
+fn derive_struct_with_path_qualified_field_types() {
+    let owner = api_doc! {
+        struct Owner {
+            name: std::string::String,
+            pet: pet_models::Pet,
+            pets: Vec<crate::pet_models::Pet>,
+            best_friend: Option<Box<self::pet_models::Pet>>,
+        }
+    };
+
+    #[cfg(not(feature = "namespaced_components"))]
+    assert_value! {owner=>
+        "properties.name.type" = r#""string""#, "Owner name type"
+        "properties.pet.$ref" = r###""#/components/schemas/Pet""###, "Owner pet ref"
+        "properties.pets.items.$ref" = r###""#/components/schemas/Pet""###, "Owner pets items ref"
+        "properties.best_friend.$ref" = r###""#/components/schemas/Pet""###, "Owner best_friend ref"
+        "required" = r#"["name","pet","pets"]"#, "Owner required fields"
+    }
+
+    #[cfg(feature = "namespaced_components")]
+    assert_value! {owner=>
+        "properties.name.type" = r#""string""#, "Owner name type"
+        "properties.pet.$ref" = r###""#/components/schemas/pet_models.Pet""###, "Owner pet ref"
+        "properties.pets.items.$ref" = r###""#/components/schemas/pet_models.Pet""###, "Owner pets items ref"
+        "properties.best_friend.$ref" = r###""#/components/schemas/pet_models.Pet""###, "Owner best_friend ref"
+        "required" = r#"["name","pet","pets"]"#, "Owner required fields"
+    }
+}
+
+#[test]
+fn derive_openapi_with_path_qualified_component() {
+    #[derive(OpenApi)]
+    #[openapi(components(crate::pet_models::Pet))]
+    struct ApiDoc;
+
+    let json = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    #[cfg(not(feature = "namespaced_components"))]
+    let pet = get_json_path(&json, "components.schemas.Pet");
+    #[cfg(feature = "namespaced_components")]
+    let pet = get_json_path(&json, "components.schemas")
+        .get("pet_models.Pet")
+        .unwrap_or(&Value::Null);
+
+    assert_value! {pet=>
+        "properties.name.type" = r#""string""#, "Pet name type"
+    }
+}
+
+#[test]
+fn derive_components_with_custom_names() {
+    #[allow(dead_code)]
+    #[derive(Component)]
+    #[component(as = v1::Pet)]
+    struct Pet {
+        name: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Component)]
+    #[component(as = AnimalKind)]
+    enum Kind {
+        Cat,
+        Dog,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Component)]
+    #[component(as = Animal)]
+    enum ComplexAnimal {
+        Cat { name: String },
+        Dog(String),
+    }
+
+    #[allow(dead_code)]
+    #[derive(Component)]
+    #[component(as = Point)]
+    struct Coordinates(i64, i64);
+
+    #[derive(OpenApi)]
+    #[openapi(components(Pet, Kind, ComplexAnimal, Coordinates))]
+    struct ApiDoc;
+
+    let json = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = get_json_path(&json, "components.schemas");
+
+    assert_eq!(schemas.get("Pet"), None, "Pet should be renamed");
+    assert_eq!(schemas.get("Kind"), None, "Kind should be renamed");
+
+    let pet = schemas.get("v1.Pet").unwrap_or(&Value::Null);
+    assert_value! {pet=>
+        "title" = r#""v1.Pet""#, "Pet title"
+        "properties.name.type" = r#""string""#, "Pet name type"
+    }
+
+    let kind = get_json_path(schemas, "AnimalKind");
+    assert_value! {kind=>
+        "title" = r#""AnimalKind""#, "Kind title"
+        "enum" = r#"["Cat","Dog"]"#, "Kind enum variants"
+    }
+
+    let animal = get_json_path(schemas, "Animal");
+    assert_value! {animal=>
+        "title" = r#""Animal""#, "ComplexAnimal title"
+        "oneOf.[0].properties.Cat.properties.name.type" = r#""string""#, "ComplexAnimal Cat name type"
+    }
+
+    let point = get_json_path(schemas, "Point");
+    assert_value! {point=>
+        "title" = r#""Point""#, "Coordinates title"
+        "type" = r#""array""#, "Coordinates type"
+        "maxItems" = r#"2"#, "Coordinates max items"
+    }
+}
+
+mod external {
+    #[allow(dead_code)]
+    pub enum Weekday {
+        Mon,
+        Tue,
+    }
+
+    #[allow(dead_code)]
+    pub struct Money {
+        pub amount: i64,
+        pub currency: String,
+    }
+}
+
+#[test]
+fn derive_components_with_remote_types() {
+    #[allow(dead_code)]
+    #[derive(Component)]
+    #[component(remote = "external::Weekday")]
+    enum WeekdayDef {
+        Mon,
+        Tue,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Component)]
+    #[component(remote = "crate::external::Money")]
+    struct MoneyDef {
+        amount: i64,
+        currency: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Component)]
+    struct Opening {
+        day: external::Weekday,
+        price: Option<external::Money>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(WeekdayDef, MoneyDef, Opening))]
+    struct ApiDoc;
+
+    let json = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = get_json_path(&json, "components.schemas");
+
+    assert_eq!(
+        schemas.get("WeekdayDef"),
+        None,
+        "WeekdayDef should be registered as remote"
+    );
+    assert_eq!(
+        schemas.get("MoneyDef"),
+        None,
+        "MoneyDef should be registered as remote"
+    );
+
+    #[cfg(not(feature = "namespaced_components"))]
+    {
+        let weekday = get_json_path(schemas, "Weekday");
+        assert_value! {weekday=>
+            "enum" = r#"["Mon","Tue"]"#, "Weekday enum variants"
+            "title" = r#"null"#, "Weekday title"
+        }
+
+        let money = get_json_path(schemas, "Money");
+        assert_value! {money=>
+            "properties.amount.type" = r#""integer""#, "Money amount type"
+            "properties.currency.type" = r#""string""#, "Money currency type"
+        }
+
+        let opening = get_json_path(schemas, "Opening");
+        assert_value! {opening=>
+            "properties.day.$ref" = r###""#/components/schemas/Weekday""###, "Opening day ref"
+            "properties.price.$ref" = r###""#/components/schemas/Money""###, "Opening price ref"
+        }
+    }
+
+    #[cfg(feature = "namespaced_components")]
+    {
+        assert!(
+            schemas.get("external.Weekday").is_some(),
+            "external.Weekday component"
+        );
+        assert!(
+            schemas.get("external.Money").is_some(),
+            "external.Money component"
+        );
+
+        let opening = get_json_path(schemas, "Opening");
+        assert_value! {opening=>
+            "properties.day.$ref" = r###""#/components/schemas/external.Weekday""###, "Opening day ref"
+            "properties.price.$ref" = r###""#/components/schemas/external.Money""###, "Opening price ref"
+        }
+    }
+}
+
+mod aliases {
+    pub type PetId = u64;
+    pub type Tags = Vec<String>;
+    pub use std::string::String as Text;
+
+    pub struct Email(#[allow(dead_code)] String);
+
+    impl utoipa::PartialComponent for Email {
+        fn partial_component() -> utoipa::openapi::schema::Component {
+            utoipa::openapi::PropertyBuilder::new()
+                .component_type(utoipa::openapi::ComponentType::String)
+                .format(Some(utoipa::openapi::ComponentFormat::Password))
+                .into()
+        }
+    }
+}
+
+#[test]
+fn derive_struct_with_partial_component_field_types() {
+    let pet = api_doc! {
+        struct Pet {
+            id: aliases::PetId,
+            name: aliases::Text,
+            tags: aliases::Tags,
+            owner_email: Option<aliases::Email>,
+            emails: Vec<aliases::Email>,
+            friend_ids: std::collections::HashMap<String, aliases::PetId>,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.id.type" = r#""integer""#, "Pet id type"
+        "properties.id.format" = r#"null"#, "Pet id format"
+        "properties.name.type" = r#""string""#, "Pet name type"
+        "properties.tags.type" = r#""array""#, "Pet tags type"
+        "properties.tags.items.type" = r#""string""#, "Pet tags items type"
+        "properties.owner_email.type" = r#""string""#, "Pet owner_email type"
+        "properties.owner_email.format" = r#""password""#, "Pet owner_email format"
+        "properties.emails.items.format" = r#""password""#, "Pet emails items format"
+        "properties.friend_ids.type" = r#""object""#, "Pet friend_ids type"
+        "required" = r#"["id","name","tags","emails","friend_ids"]"#, "Pet required fields"
+    }
+}
+
+#[test]
+fn derive_struct_with_integer_bounds() {
+    let stats = api_doc! {
+        struct Stats {
+            level: u8,
+            offset: i16,
+            count: u32,
+            total: i64,
+            id: std::num::NonZeroU32,
+            small_id: std::num::NonZeroU8,
+            small_offset: std::num::NonZeroI8,
+            pet_id: aliases::PetId,
+        }
+    };
+
+    assert_value! {stats=>
+        "properties.level.minimum" = r#"0"#, "Stats level minimum"
+        "properties.level.maximum" = r#"255"#, "Stats level maximum"
+        "properties.offset.minimum" = r#"-32768"#, "Stats offset minimum"
+        "properties.offset.maximum" = r#"32767"#, "Stats offset maximum"
+        "properties.count.minimum" = r#"0"#, "Stats count minimum"
+        "properties.count.maximum" = r#"4294967295"#, "Stats count maximum"
+        "properties.count.format" = r#""int64""#, "Stats count format"
+        "properties.total.minimum" = r#"null"#, "Stats total minimum"
+        "properties.total.maximum" = r#"null"#, "Stats total maximum"
+        "properties.id.type" = r#""integer""#, "Stats id type"
+        "properties.id.minimum" = r#"1"#, "Stats id minimum"
+        "properties.id.format" = r#""int64""#, "Stats id format"
+        "properties.id.maximum" = r#"4294967295"#, "Stats id maximum"
+        "properties.small_id.minimum" = r#"1"#, "Stats small_id minimum"
+        "properties.small_id.maximum" = r#"255"#, "Stats small_id maximum"
+        "properties.small_offset.minimum" = r#"-128"#, "Stats small_offset minimum"
+        "properties.small_offset.maximum" = r#"127"#, "Stats small_offset maximum"
+        "properties.pet_id.minimum" = r#"0"#, "Stats pet_id minimum"
+        "properties.pet_id.maximum" = r#"null"#, "Stats pet_id maximum"
+        "properties.pet_id.format" = r#"null"#, "Stats pet_id format"
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn derive_component_with_uuid_feature() {
+    let pet = api_doc! {
+        struct Pet {
+            id: uuid::Uuid,
+            owner_ids: Vec<uuid::Uuid>,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.id.type" = r#""string""#, "Pet id type"
+        "properties.id.format" = r#""uuid""#, "Pet id format"
+        "properties.owner_ids.items.type" = r#""string""#, "Pet owner_ids items type"
+        "properties.owner_ids.items.format" = r#""uuid""#, "Pet owner_ids items format"
+    }
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn derive_component_with_url_feature() {
+    let pet = api_doc! {
+        struct Pet {
+            homepage: Option<url::Url>,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.homepage.type" = r#""string""#, "Pet homepage type"
+        "properties.homepage.format" = r#""uri""#, "Pet homepage format"
+    }
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn derive_component_with_indexmap_feature() {
+    let pet = api_doc! {
+        struct Pet {
+            attributes: indexmap::IndexMap<String, String>,
+            tags: indexmap::IndexSet<String>,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.attributes.type" = r#""object""#, "Pet attributes type"
+        "properties.tags.type" = r#""array""#, "Pet tags type"
+        "properties.tags.uniqueItems" = r#"true"#, "Pet tags unique items"
+        "properties.tags.items.type" = r#""string""#, "Pet tags items type"
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn derive_component_with_bytes_feature() {
+    let file = api_doc! {
+        struct File {
+            content: bytes::Bytes,
+            thumbnail: Option<Vec<u8>>,
+            checksum: [u8; 4],
+            #[component(format = ComponentFormat::Binary)]
+            raw: Vec<u8>,
+            #[component(format = ComponentFormat::Byte)]
+            encoded: Option<bytes::Bytes>,
+        }
+    };
+
+    assert_value! {file=>
+        "properties.content.type" = r#""array""#, "File content type"
+        "properties.content.items.type" = r#""integer""#, "File content items type"
+        "properties.thumbnail.type" = r#""array""#, "File thumbnail type"
+        "properties.thumbnail.items.type" = r#""integer""#, "File thumbnail items type"
+        "properties.checksum.type" = r#""array""#, "File checksum type"
+        "properties.checksum.minItems" = r#"4"#, "File checksum min items"
+        "properties.checksum.maxItems" = r#"4"#, "File checksum max items"
+        "properties.raw.type" = r#""string""#, "File raw type"
+        "properties.raw.format" = r#""binary""#, "File raw format"
+        "properties.raw.items" = r#"null"#, "File raw items"
+        "properties.encoded.type" = r#""string""#, "File encoded type"
+        "properties.encoded.format" = r#""byte""#, "File encoded format"
+        "required" = r#"["content","checksum","raw"]"#, "File required fields"
+    }
+}
+
+#[cfg(feature = "json_value")]
+#[test]
+fn derive_component_with_json_value_feature() {
+    let event = api_doc! {
+        struct Event {
+            name: String,
+            payload: serde_json::Value,
+            metadata: Option<Value>,
+        }
+    };
+
+    assert_value! {event=>
+        "properties.payload" = r#"{}"#, "Event payload"
+        "properties.metadata" = r#"{}"#, "Event metadata"
+        "required" = r#"["name","payload"]"#, "Event required fields"
+    }
+}
+
+#[test]
+fn derive_struct_with_collection_types() {
+    use std::{
+        cell::Cell,
+        collections::{BTreeMap, BTreeSet, HashSet, LinkedList, VecDeque},
+        rc::Rc,
+        sync::{Arc, Mutex},
+    };
+
+    let pet = api_doc! {
+        struct Pet {
+            tags: HashSet<String>,
+            sorted_tags: BTreeSet<String>,
+            history: VecDeque<i64>,
+            owners: LinkedList<String>,
+            coordinates: [f64; 2],
+            nicknames: &'static [&'static str],
+            attributes: BTreeMap<String, String>,
+            name: Arc<String>,
+            age: Rc<u8>,
+            weight: Mutex<f32>,
+            alive: Cell<bool>,
+            friends: Option<Arc<Vec<Box<str>>>>,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.tags.type" = r#""array""#, "Pet tags type"
+        "properties.tags.uniqueItems" = r#"true"#, "Pet tags unique items"
+        "properties.tags.items.type" = r#""string""#, "Pet tags items type"
+        "properties.sorted_tags.type" = r#""array""#, "Pet sorted_tags type"
+        "properties.sorted_tags.uniqueItems" = r#"true"#, "Pet sorted_tags unique items"
+        "properties.history.type" = r#""array""#, "Pet history type"
+        "properties.history.uniqueItems" = r#"null"#, "Pet history unique items"
+        "properties.history.items.format" = r#""int64""#, "Pet history items format"
+        "properties.owners.type" = r#""array""#, "Pet owners type"
+        "properties.coordinates.type" = r#""array""#, "Pet coordinates type"
+        "properties.coordinates.minItems" = r#"2"#, "Pet coordinates min items"
+        "properties.coordinates.maxItems" = r#"2"#, "Pet coordinates max items"
+        "properties.coordinates.items.type" = r#""number""#, "Pet coordinates items type"
+        "properties.nicknames.type" = r#""array""#, "Pet nicknames type"
+        "properties.nicknames.minItems" = r#"null"#, "Pet nicknames min items"
+        "properties.nicknames.items.type" = r#""string""#, "Pet nicknames items type"
+        "properties.attributes.type" = r#""object""#, "Pet attributes type"
+        "properties.name.type" = r#""string""#, "Pet name type"
+        "properties.age.type" = r#""integer""#, "Pet age type"
+        "properties.age.maximum" = r#"255"#, "Pet age maximum"
+        "properties.weight.type" = r#""number""#, "Pet weight type"
+        "properties.alive.type" = r#""boolean""#, "Pet alive type"
+        "properties.friends.type" = r#""array""#, "Pet friends type"
+        "properties.friends.items.type" = r#""string""#, "Pet friends items type"
+        "required" = r#"["tags","sorted_tags","history","owners","coordinates","nicknames","attributes","name","age","weight","alive"]"#, "Pet required fields"
+    }
+}
+
+
+#[test]
+fn derive_struct_with_aliased_and_user_named_containers() {
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Map {
+        name: String,
+    }
+
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Set<T: utoipa::PartialComponent> {
+        items: Vec<T>,
+    }
+
+    type Ids = Vec<u64>;
+
+    let pet = api_doc! {
+        struct Pet {
+            ids: Ids,
+            map: Map,
+            labels: Set<String>,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.ids.type" = r#""array""#, "Pet ids type"
+        "properties.ids.items.type" = r#""integer""#, "Pet ids items type"
+        "properties.map.$ref" = r##""#/components/schemas/Map""##, "Pet map ref"
+        "properties.map.type" = r#"null"#, "Pet map type"
+        "properties.labels.$ref" = r##""#/components/schemas/Set""##, "Pet labels ref"
+        "properties.labels.uniqueItems" = r#"null"#, "Pet labels unique items"
+    }
+}
+
+#[test]
+fn derive_struct_field_attributes_on_every_component_kind() {
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    fn tags_example() -> Vec<String> {
+        vec!["cute".to_string()]
+    }
+
+    fn owner_example() -> Value {
+        serde_json::json!({"name": "bob"})
+    }
+
+    let pet = api_doc! {
+        struct Pet {
+            /// Pet tags
+            #[component(example = tags_example, read_only)]
+            tags: Vec<String>,
+            /// Pet attributes
+            #[component(default = HashMap::<String, String>::new, write_only)]
+            attributes: HashMap<String, String>,
+            /// Pet owner
+            #[component(example = owner_example)]
+            owner: Owner,
+            /// Pet location
+            location: (f64, String),
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.tags.type" = r#""array""#, "Pet tags type"
+        "properties.tags.description" = r#""Pet tags""#, "Pet tags description"
+        "properties.tags.example" = r#"["cute"]"#, "Pet tags example"
+        "properties.tags.readOnly" = r#"true"#, "Pet tags read only"
+        "properties.tags.items.description" = r#"null"#, "Pet tags items description"
+        "properties.attributes.type" = r#""object""#, "Pet attributes type"
+        "properties.attributes.description" = r#""Pet attributes""#, "Pet attributes description"
+        "properties.attributes.default" = r#"{}"#, "Pet attributes default"
+        "properties.attributes.writeOnly" = r#"true"#, "Pet attributes write only"
+        "properties.owner.$ref" = r#"null"#, "Pet owner ref"
+        "properties.owner.oneOf.[0].$ref" = r##""#/components/schemas/Owner""##, "Pet owner oneOf ref"
+        "properties.owner.description" = r#""Pet owner""#, "Pet owner description"
+        "properties.owner.example" = r#"{"name":"bob"}"#, "Pet owner example"
+        "properties.location.type" = r#""array""#, "Pet location type"
+        "properties.location.description" = r#""Pet location""#, "Pet location description"
+    }
+}
+#[test]
+fn derive_struct_with_inline_fields() {
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    let pet = api_doc! {
+        struct Pet {
+            #[component(inline)]
+            owner: Owner,
+            #[component(inline)]
+            previous_owners: Vec<Owner>,
+            #[component(inline)]
+            co_owner: Option<Owner>,
+            breeder: Owner,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.owner.$ref" = r#"null"#, "Pet owner ref"
+        "properties.owner.type" = r#""object""#, "Pet owner type"
+        "properties.owner.properties.name.type" = r#""string""#, "Pet owner name type"
+        "properties.previous_owners.type" = r#""array""#, "Pet previous_owners type"
+        "properties.previous_owners.items.properties.name.type" = r#""string""#, "Pet previous_owners items name type"
+        "properties.co_owner.properties.name.type" = r#""string""#, "Pet co_owner name type"
+        "properties.breeder.$ref" = r##""#/components/schemas/Owner""##, "Pet breeder ref"
+        "required" = r#"["owner","previous_owners","breeder"]"#, "Pet required fields"
+    }
+}
+
+#[test]
+fn derive_struct_with_inline_field_attributes() {
+    /// Owner of a pet
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    let pet = api_doc! {
+        struct Pet {
+            /// Current owner of the pet
+            #[component(inline, read_only)]
+            owner: Owner,
+            #[component(inline, description = "Previous owners of the pet")]
+            previous_owners: Vec<Owner>,
+            #[component(inline)]
+            breeder: Owner,
+        }
+    };
+
+    assert_value! {pet=>
+        "properties.owner.type" = r#""object""#, "Pet owner type"
+        "properties.owner.description" = r#""Current owner of the pet""#, "Pet owner description"
+        "properties.owner.readOnly" = r#"true"#, "Pet owner read only"
+        "properties.owner.properties.name.type" = r#""string""#, "Pet owner name type"
+        "properties.previous_owners.type" = r#""array""#, "Pet previous_owners type"
+        "properties.previous_owners.description" = r#""Previous owners of the pet""#, "Pet previous_owners description"
+        "properties.previous_owners.items.description" = r#""Owner of a pet""#, "Pet previous_owners items description"
+        "properties.breeder.description" = r#""Owner of a pet""#, "Pet breeder description"
+    }
+}
+
+#[test]
+fn derive_tuple_types_as_fixed_shape_arrays() {
+    let route = api_doc! {
+        struct Route {
+            start: (f64, f64),
+            label: Option<(i32, String)>,
+            stops: Vec<(String, u8, bool)>,
+        }
+    };
+
+    assert_value! {route=>
+        "properties.start.type" = r#""array""#, "Route start type"
+        "properties.start.items.type" = r#""number""#, "Route start items type"
+        "properties.start.items.format" = r#""float""#, "Route start items format"
+        "properties.start.items.oneOf" = r#"null"#, "Route start items one of"
+        "properties.start.minItems" = r#"2"#, "Route start min items"
+        "properties.start.maxItems" = r#"2"#, "Route start max items"
+        "properties.label.type" = r#""array""#, "Route label type"
+        "properties.label.items.oneOf" = r#"[{"format":"int32","type":"integer"},{"type":"string"}]"#, "Route label items"
+        "properties.stops.type" = r#""array""#, "Route stops type"
+        "properties.stops.items.type" = r#""array""#, "Route stops items type"
+        "properties.stops.items.minItems" = r#"3"#, "Route stops items min items"
+        "properties.stops.items.maxItems" = r#"3"#, "Route stops items max items"
+        "required" = r#"["start","stops"]"#, "Route required fields"
+    }
+}
+
+#[test]
+fn derive_tuple_types_with_description_deprecated_and_example() {
+    fn label_example() -> (i32, &'static str) {
+        (1, "first")
+    }
+
+    fn origin() -> (f64, &'static str) {
+        (1.0, "origin")
+    }
+
+    #[allow(deprecated)]
+    let route = api_doc! {
+        struct Route {
+            /// Label of the route
+            #[deprecated]
+            #[component(example = label_example, read_only)]
+            label: (i32, String),
+        }
+    };
+
+    assert_value! {route=>
+        "properties.label.type" = r#""array""#, "Route label type"
+        "properties.label.description" = r#""Label of the route""#, "Route label description"
+        "properties.label.deprecated" = r#"true"#, "Route label deprecated"
+        "properties.label.example" = r#"[1,"first"]"#, "Route label example"
+        "properties.label.readOnly" = r#"true"#, "Route label read only"
+        "properties.label.items.description" = r#"null"#, "Route label items description"
+    }
+
+    #[allow(deprecated)]
+    let point = api_doc! {
+        /// Point in a plane
+        #[deprecated]
+        #[component(example = origin)]
+        struct Point(f64, String);
+    };
+
+    assert_value! {point=>
+        "type" = r#""array""#, "Point type"
+        "description" = r#""Point in a plane""#, "Point description"
+        "deprecated" = r#"true"#, "Point deprecated"
+        "example" = r#"[1.0,"origin"]"#, "Point example"
+        "items.description" = r#"null"#, "Point items description"
+    }
+}
+
+#[test]
+fn derive_complex_enum_with_tuple_variant_as_fixed_shape_array() {
+    let shape = api_doc! {
+        enum Shape {
+            Circle(f64),
+            Rectangle(f64, String),
+        }
+    };
+
+    let one_of = shape.get("oneOf").unwrap();
+    let rectangle = &one_of[1];
+
+    assert_value! {rectangle=>
+        "properties.Rectangle.type" = r#""array""#, "Shape Rectangle type"
+        "properties.Rectangle.items.oneOf" = r#"[{"format":"float","type":"number"},{"type":"string"}]"#, "Shape Rectangle items"
+        "properties.Rectangle.minItems" = r#"2"#, "Shape Rectangle min items"
+        "properties.Rectangle.maxItems" = r#"2"#, "Shape Rectangle max items"
+    }
+}
+
+#[cfg(feature = "openapi_31")]
+#[test]
+fn derive_tuple_types_with_prefix_items_with_openapi_31_feature() {
+    let point = api_doc! {
+        struct Point(f64, String);
+    };
+
+    assert_value! {point=>
+        "prefixItems" = r#"[{"format":"float","type":"number"},{"type":"string"}]"#, "Point prefix items"
+        "minItems" = r#"2"#, "Point min items"
+        "maxItems" = r#"2"#, "Point max items"
+    }
+}
@@ -44,3 +44,54 @@ fn modify_openapi_add_security_schema() {
         "components.securitySchemes.api_jwt_token.bearerFormat" = r###""JWT""###, "api_jwt_token bearerFormat"
     }
 }
+
+#[test]
+fn modify_openapi_fill_examples() {
+    use utoipa::{openapi::example::FillExamples, Component};
+
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(Component)]
+    #[allow(unused)]
+    struct Pet {
+        id: u64,
+        #[component(example = "doggo")]
+        name: String,
+        owner: Owner,
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets/{id}",
+        request_body = Pet,
+        responses(
+            (status = 200, description = "Pet updated", body = [Pet])
+        ),
+        params(
+            ("id" = u64, path, description = "Pet id")
+        )
+    )]
+    #[allow(unused)]
+    fn update_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        handlers(update_pet),
+        components(Pet, Owner),
+        modifiers(&FillExamples)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let operation = common::get_json_path(&doc, "paths./pets/{id}.post");
+
+    assert_value! {operation=>
+        "requestBody.content.application/json.example" = r###"{"id":0,"name":"doggo","owner":{"name":"string"}}"###, "Request body example"
+        "responses.200.content.application/json.example" = r###"[{"id":0,"name":"doggo","owner":{"name":"string"}}]"###, "Response example"
+        "parameters.[0].example" = r###"0"###, "Parameter example"
+    }
+}
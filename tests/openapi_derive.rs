@@ -10,7 +10,8 @@ fn derive_openapi_with_security_requirement() {
     #[openapi(security(
             (),
             ("my_auth" = ["read:items", "edit:items"]),
-            ("token_jwt" = [])
+            ("token_jwt" = []),
+            ("api_key" = [], "my_auth" = ["read:items"])
         ))]
     struct ApiDoc;
 
@@ -21,6 +22,8 @@ fn derive_openapi_with_security_requirement() {
         "security.[1].my_auth.[0]" = r###""read:items""###, "api_oauth first scope"
         "security.[1].my_auth.[1]" = r###""edit:items""###, "api_oauth second scope"
         "security.[2].token_jwt" = "[]", "jwt_token auth scopes"
+        "security.[3].api_key" = "[]", "api_key auth scopes"
+        "security.[3].my_auth" = r###"["read:items"]"###, "my_auth auth scopes with api_key"
     }
 }
 
@@ -78,3 +81,427 @@ fn derive_openapi_with_external_docs_only_url() {
         "externalDocs.description" = r###"null"###, "External docs description"
     }
 }
+
+mod versioned {
+    pub mod v1 {
+        #[allow(dead_code)]
+        #[derive(utoipa::Component)]
+        #[component(as = PetV1)]
+        pub struct Pet {
+            name: String,
+        }
+
+        #[allow(dead_code)]
+        #[derive(utoipa::Component)]
+        pub struct Owner {
+            pet: crate::versioned::v1::Pet,
+            pets: Vec<crate::versioned::v1::Pet>,
+        }
+
+        #[utoipa::path(
+            post,
+            path = "/v1/pets",
+            request_body = crate::versioned::v1::Pet,
+            responses(
+                (status = 200, description = "Created pets", body = [crate::versioned::v1::Pet])
+            )
+        )]
+        #[allow(unused)]
+        fn create_pet() {}
+    }
+
+    pub mod v2 {
+        #[allow(dead_code)]
+        #[derive(utoipa::Component)]
+        #[component(as = PetV2)]
+        pub struct Pet {
+            name: String,
+            age: i32,
+        }
+
+        #[utoipa::path(
+            post,
+            path = "/v2/pets",
+            request_body = Pet,
+            responses(
+                (status = 200, description = "Created pets", body = [Pet])
+            ),
+            params(
+                ("filter" = Pet, query, description = "Filter of the pets")
+            )
+        )]
+        #[allow(unused)]
+        fn create_pet() {}
+    }
+}
+
+#[test]
+fn derive_openapi_with_renamed_component_refs() {
+    #[derive(OpenApi)]
+    #[openapi(
+        handlers(versioned::v1::create_pet),
+        components(versioned::v1::Pet, versioned::v1::Owner)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    #[cfg(not(feature = "namespaced_components"))]
+    let owner = common::get_json_path(&doc, "components.schemas.Owner");
+    #[cfg(feature = "namespaced_components")]
+    let owner = common::get_json_path(&doc, "components.schemas")
+        .get("versioned.v1.Owner")
+        .unwrap();
+
+    assert_value! {doc=>
+        "components.schemas.PetV1.title" = r###""PetV1""###, "PetV1 title"
+        "components.schemas.Pet" = r###"null"###, "Pet default name"
+        "paths./v1/pets.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/PetV1""###, "Request body ref"
+        "paths./v1/pets.post.responses.200.content.application/json.schema.items.$ref" = r###""#/components/schemas/PetV1""###, "Response body items ref"
+    }
+
+    assert_value! {owner=>
+        "properties.pet.$ref" = r###""#/components/schemas/PetV1""###, "Owner pet ref"
+        "properties.pets.items.$ref" = r###""#/components/schemas/PetV1""###, "Owner pets items ref"
+    }
+}
+
+#[test]
+fn derive_openapi_with_same_named_aliased_components() {
+    #[derive(OpenApi)]
+    #[openapi(
+        handlers(versioned::v1::create_pet, versioned::v2::create_pet),
+        components(versioned::v1::Pet, versioned::v2::Pet)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "components.schemas.PetV1.properties.age" = r###"null"###, "PetV1 age"
+        "components.schemas.PetV2.properties.age.type" = r###""integer""###, "PetV2 age type"
+        "components.schemas.Pet" = r###"null"###, "Pet default name"
+        "paths./v1/pets.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/PetV1""###, "V1 request body ref"
+        "paths./v2/pets.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/PetV2""###, "V2 request body ref"
+        "paths./v2/pets.post.responses.200.content.application/json.schema.items.$ref" = r###""#/components/schemas/PetV2""###, "V2 response body items ref"
+        "paths./v2/pets.post.parameters.[0].schema.$ref" = r###""#/components/schemas/PetV2""###, "V2 parameter ref"
+    }
+}
+
+#[test]
+fn derive_openapi_with_aliased_component_refs_without_registering_component() {
+    #[derive(OpenApi)]
+    #[openapi(handlers(versioned::v2::create_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths./v2/pets.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/PetV2""###, "Request body ref"
+    }
+}
+
+mod manual {
+    pub mod models {
+        pub struct Pet;
+
+        impl utoipa::Component for Pet {
+            fn component() -> utoipa::openapi::schema::Component {
+                utoipa::openapi::ObjectBuilder::new()
+                    .property(
+                        "name",
+                        utoipa::openapi::Property::new(utoipa::openapi::ComponentType::String),
+                    )
+                    .into()
+            }
+        }
+    }
+
+    pub mod handlers {
+        use super::models::Pet;
+
+        #[utoipa::path(
+            get,
+            path = "/pets",
+            responses(
+                (status = 200, description = "Pet found", body = Pet)
+            )
+        )]
+        #[allow(unused)]
+        fn get_pet() {}
+    }
+}
+
+#[test]
+fn derive_openapi_with_component_refs_resolved_from_type() {
+    #[derive(OpenApi)]
+    #[openapi(
+        handlers(manual::handlers::get_pet),
+        components(crate::manual::models::Pet)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    #[cfg(not(feature = "namespaced_components"))]
+    let name = "Pet";
+    #[cfg(feature = "namespaced_components")]
+    let name = "manual.models.Pet";
+
+    assert!(
+        common::get_json_path(&doc, "components.schemas")
+            .get(name)
+            .is_some(),
+        "Pet should be registered as {}",
+        name
+    );
+    assert_eq!(
+        common::get_json_path(
+            &doc,
+            "paths./pets.get.responses.200.content.application/json.schema.$ref"
+        ),
+        &format!("#/components/schemas/{}", name),
+        "Response body ref"
+    );
+}
+
+mod pets {
+    use std::collections::HashMap;
+
+    use utoipa::{Component, Create, Read};
+
+    #[derive(Component)]
+    #[component(split_read_write)]
+    #[allow(unused)]
+    pub struct Pet {
+        #[component(read_only)]
+        pub id: i64,
+        pub name: String,
+        #[component(write_only)]
+        pub secret: String,
+        #[component(read_only)]
+        pub tags: Vec<String>,
+        #[component(write_only)]
+        pub aliases: Option<Vec<String>>,
+        #[component(read_only)]
+        pub labels: HashMap<String, String>,
+    }
+
+    #[derive(Component)]
+    #[component(split_read_write, as = Animal)]
+    #[allow(unused)]
+    pub struct Dog {
+        #[component(read_only)]
+        pub id: i64,
+        pub name: String,
+    }
+
+    // models are not generated to the module thus user types with the same names do not collide
+    #[allow(unused)]
+    pub struct PetCreate;
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        request_body = Create<Pet>,
+        responses(
+            (status = 200, description = "Pet created", body = Read<Pet>)
+        )
+    )]
+    #[allow(unused)]
+    fn create_pet() {}
+
+    #[utoipa::path(
+        post,
+        path = "/dogs",
+        request_body = utoipa::Create<Dog>,
+        responses(
+            (status = 200, description = "Dog created", body = [utoipa::Read<Dog>])
+        )
+    )]
+    #[allow(unused)]
+    fn create_dog() {}
+}
+
+#[test]
+fn derive_openapi_with_split_read_write_components() {
+    #[derive(OpenApi)]
+    #[openapi(handlers(pets::create_pet), components(pets::Pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    #[cfg(not(feature = "namespaced_components"))]
+    assert_value! {doc=>
+        "components.schemas.PetCreate.properties.id" = r###"null"###, "PetCreate id"
+        "components.schemas.PetCreate.properties.secret.writeOnly" = r###"true"###, "PetCreate secret"
+        "components.schemas.PetCreate.properties.tags" = r###"null"###, "PetCreate tags"
+        "components.schemas.PetCreate.properties.aliases.type" = r###""array""###, "PetCreate aliases"
+        "components.schemas.PetCreate.properties.labels" = r###"null"###, "PetCreate labels"
+        "components.schemas.PetCreate.required" = r###"["name","secret"]"###, "PetCreate required"
+        "components.schemas.PetRead.properties.id.readOnly" = r###"true"###, "PetRead id"
+        "components.schemas.PetRead.properties.secret" = r###"null"###, "PetRead secret"
+        "components.schemas.PetRead.properties.tags.type" = r###""array""###, "PetRead tags"
+        "components.schemas.PetRead.properties.aliases" = r###"null"###, "PetRead aliases"
+        "components.schemas.PetRead.properties.labels.type" = r###""object""###, "PetRead labels"
+        "components.schemas.PetRead.required" = r###"["id","name","tags","labels"]"###, "PetRead required"
+        "paths./pets.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/PetCreate""###, "Request body ref"
+        "paths./pets.post.responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/PetRead""###, "Response body ref"
+    }
+    #[cfg(feature = "namespaced_components")]
+    {
+        let schemas = common::get_json_path(&doc, "components.schemas");
+        assert!(
+            schemas.get("pets.PetCreate").is_some(),
+            "pets.PetCreate component"
+        );
+        assert!(
+            schemas.get("pets.PetRead").is_some(),
+            "pets.PetRead component"
+        );
+        assert_value! {doc=>
+            "paths./pets.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/pets.PetCreate""###, "Request body ref"
+            "paths./pets.post.responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/pets.PetRead""###, "Response body ref"
+        }
+    }
+}
+
+#[test]
+fn derive_openapi_with_aliased_split_read_write_components() {
+    #[derive(OpenApi)]
+    #[openapi(handlers(pets::create_dog), components(pets::Dog))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "components.schemas.AnimalCreate.properties.id" = r###"null"###, "AnimalCreate id"
+        "components.schemas.AnimalCreate.required" = r###"["name"]"###, "AnimalCreate required"
+        "components.schemas.AnimalRead.required" = r###"["id","name"]"###, "AnimalRead required"
+        "components.schemas.DogCreate" = r###"null"###, "DogCreate"
+        "paths./dogs.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/AnimalCreate""###, "Request body ref"
+        "paths./dogs.post.responses.200.content.application/json.schema.items.$ref" = r###""#/components/schemas/AnimalRead""###, "Response body items ref"
+    }
+}
+
+#[cfg(feature = "validation")]
+#[test]
+fn derive_openapi_components_validate_values() {
+    use serde::Serialize;
+    use utoipa::{openapi::validation, Component};
+
+    #[derive(Serialize, Component)]
+    #[allow(unused)]
+    enum Status {
+        Available,
+        Sold,
+    }
+
+    #[derive(Serialize, Component)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(Serialize, Component)]
+    struct Pet {
+        id: u64,
+        status: Status,
+        owner: Owner,
+        nickname: Option<String>,
+        tags: Vec<String>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(Pet, Owner, Status))]
+    struct ApiDoc;
+
+    let components = ApiDoc::openapi().components.unwrap();
+    let pet = Pet {
+        id: 1,
+        status: Status::Available,
+        owner: Owner {
+            name: "Jane".to_string(),
+        },
+        nickname: None,
+        tags: vec!["good".to_string()],
+    };
+
+    assert!(validation::validate(
+        &Pet::component(),
+        &components,
+        &serde_json::to_value(&pet).unwrap()
+    )
+    .is_ok());
+
+    let invalid = serde_json::json!({
+        "id": -1,
+        "status": "Lost",
+        "owner": {},
+        "tags": "good"
+    });
+    let errors = validation::validate(&Pet::component(), &components, &invalid)
+        .unwrap_err()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        errors,
+        [
+            "/id: value is less than minimum 0",
+            "/owner: missing required property `name`",
+            "/status: value is not one of the enum values",
+            "/tags: expected array but found string",
+        ]
+    );
+}
+
+#[cfg(all(feature = "proptest", feature = "validation"))]
+#[test]
+fn derive_openapi_components_generate_valid_values() {
+    use proptest::test_runner::TestRunner;
+    use serde::Deserialize;
+    use utoipa::{
+        openapi::{proptest::strategy, validation},
+        Component,
+    };
+
+    #[derive(Deserialize, Component)]
+    #[allow(unused)]
+    enum Status {
+        Available,
+        Sold,
+    }
+
+    #[derive(Deserialize, Component)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+        age: u8,
+    }
+
+    #[derive(Deserialize, Component)]
+    #[allow(unused)]
+    struct Pet {
+        id: u64,
+        status: Status,
+        owner: Owner,
+        nickname: Option<String>,
+        tags: Vec<String>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(Pet, Owner, Status))]
+    struct ApiDoc;
+
+    let components = ApiDoc::openapi().components.unwrap();
+    let pet = Pet::component();
+
+    TestRunner::default()
+        .run(&strategy(&pet, &components).unwrap(), |value| {
+            assert_eq!(validation::validate(&pet, &components, &value), Ok(()));
+            assert!(serde_json::from_value::<Pet>(value).is_ok());
+            Ok(())
+        })
+        .unwrap();
+}
@@ -186,7 +186,7 @@ fn derive_path_with_extra_attributes_without_nested_module() {
         "parameters.[0].in" = r#""path""#, "Parameter 0 in"
         "parameters.[0].name" = r#""id""#, "Parameter 0 name"
         "parameters.[0].required" = r#"true"#, "Parameter 0 required"
-        "parameters.[0].schema.format" = r#""int64""#, "Parameter 0 schema format"
+        "parameters.[0].schema.format" = r#"null"#, "Parameter 0 schema format"
         "parameters.[0].schema.type" = r#""integer""#, "Parameter 0 schema type"
 
         "parameters.[1].deprecated" = r#"false"#, "Parameter 1 deprecated"
@@ -230,3 +230,33 @@ fn derive_path_with_security_requirements() {
         "security.[2].jwt_token" = "[]", "jwt_token auth scopes"
     }
 }
+
+#[test]
+fn derive_path_with_multiple_schemes_security_requirement() {
+    #[utoipa::path(
+        get,
+        path = "/items",
+        responses(
+            (status = 200, description = "success response")
+        ),
+        security(
+            ("api_key" = [], "api_oauth" = ["read:items"]),
+            ("jwt_token" = [])
+        )
+    )]
+    #[allow(unused)]
+    fn get_items() -> String {
+        "".to_string()
+    }
+    let operation = test_api_fn_doc! {
+        get_items,
+        operation: get,
+        path: "/items"
+    };
+
+    assert_value! {operation=>
+        "security.[0].api_key" = "[]", "api_key auth scopes"
+        "security.[0].api_oauth" = r###"["read:items"]"###, "api_oauth auth scopes"
+        "security.[1].jwt_token" = "[]", "jwt_token auth scopes"
+    }
+}
@@ -138,6 +138,8 @@ fn derive_path_parameter_multiple_success() {
 }
 
 mod mod_derive_parameters_all_types {
+    type FooId = u64;
+
     /// Get foo by id
     ///
     /// Get foo by id long description
@@ -153,6 +155,7 @@ mod mod_derive_parameters_all_types {
             ("numbers" = Option<[u64]>, query, description = "Foo numbers list"),
             ("token" = String, header, deprecated, description = "Token of foo"),
             ("cookieval" = String, cookie, deprecated, description = "Foo cookie"),
+            ("owner" = FooId, query, description = "Foo owner id"),
         )
     )]
     #[allow(unused)]
@@ -170,7 +173,7 @@ fn derive_parameters_with_all_types() {
     let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
     let parameters = common::get_json_path(&doc, "paths./foo/{id}.get.parameters");
 
-    common::assert_json_array_len(parameters, 5);
+    common::assert_json_array_len(parameters, 6);
     assert_value! {parameters=>
         "[0].in" = r#""path""#, "Parameter in"
         "[0].name" = r#""id""#, "Parameter name"
@@ -196,7 +199,7 @@ fn derive_parameters_with_all_types() {
         "[2].schema.type" = r#""array""#, "Parameter schema type"
         "[2].schema.format" = r#"null"#, "Parameter schema format"
         "[2].schema.items.type" = r#""integer""#, "Parameter schema items type"
-        "[2].schema.items.format" = r#""int64""#, "Parameter schema items format"
+        "[2].schema.items.format" = r#"null"#, "Parameter schema items format"
 
         "[3].in" = r#""header""#, "Parameter in"
         "[3].name" = r#""token""#, "Parameter name"
@@ -213,6 +216,11 @@ fn derive_parameters_with_all_types() {
         "[4].deprecated" = r#"true"#, "Parameter deprecated"
         "[4].schema.type" = r#""string""#, "Parameter schema type"
         "[4].schema.format" = r#"null"#, "Parameter schema format"
+
+        "[5].in" = r#""query""#, "Parameter in"
+        "[5].name" = r#""owner""#, "Parameter name"
+        "[5].schema.type" = r#""integer""#, "Parameter schema type"
+        "[5].schema.format" = r#"null"#, "Parameter schema format"
     };
 }
 
@@ -253,3 +261,148 @@ fn derive_params_without_fn_args() {
         "[0].schema.format" = r#""int32""#, "Parameter schema format"
     };
 }
+
+mod derive_params_with_example_function {
+    pub fn tags() -> Vec<&'static str> {
+        vec!["cat", "dog"]
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("tags" = [String], query, example = self::tags),
+            ("limit" = i32, query, example = crate::derive_params_with_example_function::limit),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    pub fn limit() -> i32 {
+        10
+    }
+}
+
+#[test]
+fn derive_params_with_example_function() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_params_with_example_function::get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    assert_value! {parameters=>
+        "[0].example" = r#"["cat","dog"]"#, "Parameter tags example"
+        "[1].example" = r#"10"#, "Parameter limit example"
+    };
+}
+
+mod derive_params_with_style {
+    #[derive(utoipa::Component)]
+    #[allow(unused)]
+    struct PetFilter {
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("tags" = [String], query, style = Form, explode, example = json!(["cat", "dog"])),
+            ("ids" = [i64], query, style = Form, explode = false, allow_reserved),
+            ("filter" = PetFilter, query, style = DeepObject, explode),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+}
+
+#[test]
+fn derive_params_with_style_and_explode() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_params_with_style::get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    common::assert_json_array_len(parameters, 3);
+    assert_value! {parameters=>
+        "[0].in" = r#""query""#, "Parameter in"
+        "[0].name" = r#""tags""#, "Parameter name"
+        "[0].style" = r#""form""#, "Parameter style"
+        "[0].explode" = r#"true"#, "Parameter explode"
+        "[0].allowReserved" = r#"null"#, "Parameter allow reserved"
+        "[0].example" = r#"["cat","dog"]"#, "Parameter example"
+        "[0].schema.type" = r#""array""#, "Parameter schema type"
+        "[0].schema.items.type" = r#""string""#, "Parameter schema items type"
+
+        "[1].name" = r#""ids""#, "Parameter name"
+        "[1].style" = r#""form""#, "Parameter style"
+        "[1].explode" = r#"false"#, "Parameter explode"
+        "[1].allowReserved" = r#"true"#, "Parameter allow reserved"
+
+        "[2].name" = r#""filter""#, "Parameter name"
+        "[2].style" = r#""deepObject""#, "Parameter style"
+        "[2].explode" = r#"true"#, "Parameter explode"
+    };
+
+    #[cfg(not(feature = "namespaced_components"))]
+    assert_value! {parameters=>
+        "[2].schema.$ref" = r##""#/components/schemas/PetFilter""##, "Parameter schema ref"
+    };
+
+    #[cfg(feature = "namespaced_components")]
+    assert_value! {parameters=>
+        "[2].schema.$ref" = r##""#/components/schemas/derive_params_with_style.PetFilter""##, "Parameter schema ref"
+    };
+}
+
+mod derive_params_with_content {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("tags" = Option<[String]>, query, content = "application/json", description = "JSON encoded tags"),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+}
+
+#[test]
+fn derive_params_with_content() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_params_with_content::get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    common::assert_json_array_len(parameters, 1);
+    assert_value! {parameters=>
+        "[0].in" = r#""query""#, "Parameter in"
+        "[0].name" = r#""tags""#, "Parameter name"
+        "[0].description" = r#""JSON encoded tags""#, "Parameter description"
+        "[0].required" = r#"false"#, "Parameter required"
+        "[0].schema" = r#"null"#, "Parameter schema"
+        "[0].content.application/json.schema.type" = r#""array""#, "Parameter content schema type"
+        "[0].content.application/json.schema.items.type" = r#""string""#, "Parameter content schema items type"
+    };
+}
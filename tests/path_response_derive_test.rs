@@ -5,6 +5,8 @@ macro_rules! test_fn {
     ( module: $name:ident, responses: $($responses:tt)* ) => {
         #[allow(unused)]
         mod $name {
+            use crate::Foo;
+
             #[utoipa::path(get,path = "/foo",responses $($responses)*)]
             fn get_foo() {}
         }
@@ -108,6 +110,9 @@ struct Foo {
     name: String,
 }
 
+#[allow(unused)]
+type FooId = u64;
+
 test_response_types! {
 primitive_string_body => body: String, assert:
     "responses.200.content.text/plain.schema.type" = r#""string""#, "Response content type"
@@ -129,6 +134,14 @@ primitive_big_integer_body => body: u128, assert:
     "responses.200.content.text/plain.schema.type" = r#""integer""#, "Response content type"
     "responses.200.content.text/plain.schema.format" = r#"null"#, "Response content format"
     "responses.200.headers" = r###"null"###, "Response headers"
+type_alias_body => body: crate::FooId, assert:
+    "responses.200.content.text/plain.schema.type" = r#""integer""#, "Response content type"
+    "responses.200.content.text/plain.schema.format" = r#"null"#, "Response content format"
+type_alias_header => headers: (
+    ("x-foo-id" = crate::FooId),
+), assert:
+    "responses.200.headers.x-foo-id.schema.type" = r###""integer""###, "x-foo-id header type"
+    "responses.200.headers.x-foo-id.schema.minimum" = r###"0"###, "x-foo-id header minimum"
 primitive_bool_body => body: bool, assert:
     "responses.200.content.text/plain.schema.type" = r#""boolean""#, "Response content type"
     "responses.200.headers" = r###"null"###, "Response headers"
@@ -139,6 +152,8 @@ object_slice_body => body: [Foo], assert:
     "responses.200.content.application/json.schema.type" = r###""array""###, "Response content type"
     "responses.200.content.application/json.schema.items.$ref" = r###""#/components/schemas/Foo""###, "Response content items type"
     "responses.200.headers" = r###"null"###, "Response headers"
+object_body_with_path_qualified_type => body: crate::Foo, assert:
+    "responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/Foo""###, "Response content type"
 object_body_override_content_type_to_xml => body: Foo, "text/xml", assert:
     "responses.200.content.application/json.schema.$ref" = r###"null"###, "Response content type"
     "responses.200.content.text/xml.schema.$ref" = r###""#/components/schemas/Foo""###, "Response content type"
@@ -149,6 +164,7 @@ object_body_with_simple_header => body: Foo, headers: (
     "responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/Foo""###, "Response content type"
     "responses.200.headers.xsrf-token.schema.type" = r###""string""###, "xsrf-token header type"
     "responses.200.headers.xsrf-token.description" = r###"null"###, "xsrf-token header description"
+    "responses.200.headers.xsrf-token.required" = r###"null"###, "xsrf-token header required"
 object_body_with_multiple_headers => body: Foo, headers: (
     ("xsrf-token"),
     ("another-header")
@@ -165,7 +181,8 @@ object_body_with_header_with_type => body: Foo, headers: (
     "responses.200.headers.random-digits.schema.type" = r###""array""###, "random-digits header type"
     "responses.200.headers.random-digits.description" = r###"null"###, "random-digits header description"
     "responses.200.headers.random-digits.schema.items.type" = r###""integer""###, "random-digits header items type"
-    "responses.200.headers.random-digits.schema.items.format" = r###""int64""###, "random-digits header items format"
+    "responses.200.headers.random-digits.schema.items.format" = r###"null"###, "random-digits header items format"
+    "responses.200.headers.random-digits.required" = r###"true"###, "random-digits header required"
 response_no_body_with_complex_header_with_description => headers: (
     ("random-digits" = [u64], description = "Random digits response header"),
 ), assert:
@@ -173,7 +190,13 @@ response_no_body_with_complex_header_with_description => headers: (
     "responses.200.headers.random-digits.description" = r###""Random digits response header""###, "random-digits header description"
     "responses.200.headers.random-digits.schema.type" = r###""array""###, "random-digits header type"
     "responses.200.headers.random-digits.schema.items.type" = r###""integer""###, "random-digits header items type"
-    "responses.200.headers.random-digits.schema.items.format" = r###""int64""###, "random-digits header items format"
+    "responses.200.headers.random-digits.schema.items.format" = r###"null"###, "random-digits header items format"
+response_with_required_component_header => headers: (
+    ("x-foo" = Foo, required),
+), assert:
+    "responses.200.headers.x-foo.schema.$ref" = r###""#/components/schemas/Foo""###, "x-foo header ref"
+    "responses.200.headers.x-foo.required" = r###"true"###, "x-foo header required"
+    "responses.200.headers.x-foo.deprecated" = r###"null"###, "x-foo header deprecated"
 }
 
 test_fn! {
@@ -195,6 +218,35 @@ fn derive_response_with_json_example_success() {
     }
 }
 
+mod examples {
+    pub fn foo() -> serde_json::Value {
+        serde_json::json!({"foo": "baz"})
+    }
+
+    pub fn rate_limit() -> i32 {
+        10
+    }
+}
+
+test_fn! {
+    module: response_with_example_function,
+    responses: (
+        (status = 200, description = "success", body = Foo, example = crate::examples::foo, headers(
+            ("x-rate-limit" = i32, example = crate::examples::rate_limit)
+        ))
+    )
+}
+
+#[test]
+fn derive_response_with_example_function_success() {
+    let doc = api_doc!(module: response_with_example_function);
+
+    assert_value! {doc=>
+        "responses.200.content.application/json.example" = r###"{"foo":"baz"}"###, "Response content example"
+        "responses.200.headers.x-rate-limit.example" = r###"10"###, "x-rate-limit header example"
+    }
+}
+
 #[test]
 fn derive_reponse_multiple_content_types() {
     test_fn! {
@@ -215,3 +267,56 @@ fn derive_reponse_multiple_content_types() {
         "responses.200.headers" = r#"null"#, "Response headers"
     }
 }
+
+#[test]
+fn derive_response_with_optional_header_with_all_attributes() {
+    test_fn! {
+        module: response_optional_header_all_attributes,
+        responses: (
+            (status = 200, description = "success", headers(
+                ("x-rate-limit" = Option<i32>, description = "Requests left", deprecated,
+                    style = Simple, explode = false, example = json!(10))
+            ))
+        )
+    }
+
+    let doc = api_doc!(module: response_optional_header_all_attributes);
+
+    assert_value! {doc=>
+        "responses.200.headers.x-rate-limit.schema.type" = r###""integer""###, "x-rate-limit header type"
+        "responses.200.headers.x-rate-limit.description" = r###""Requests left""###, "x-rate-limit header description"
+        "responses.200.headers.x-rate-limit.required" = r###"false"###, "x-rate-limit header required"
+        "responses.200.headers.x-rate-limit.deprecated" = r###"true"###, "x-rate-limit header deprecated"
+        "responses.200.headers.x-rate-limit.style" = r###""simple""###, "x-rate-limit header style"
+        "responses.200.headers.x-rate-limit.explode" = r###"false"###, "x-rate-limit header explode"
+        "responses.200.headers.x-rate-limit.example" = r###"10"###, "x-rate-limit header example"
+    }
+}
+
+#[derive(utoipa::Component)]
+#[allow(unused)]
+struct Bar {
+    name: String,
+}
+
+#[test]
+fn derive_response_with_inline_body() {
+    test_fn! {
+        module: response_inline_body,
+        responses: (
+            (status = 200, description = "success", body = inline(crate::Bar)),
+            (status = 201, description = "created", body = inline([crate::Bar]))
+        )
+    }
+
+    let doc = api_doc!(module: response_inline_body);
+
+    assert_value! {doc=>
+        "responses.200.content.application/json.schema.$ref" = r###"null"###, "Response content ref"
+        "responses.200.content.application/json.schema.type" = r###""object""###, "Response content type"
+        "responses.200.content.application/json.schema.properties.name.type" = r###""string""###, "Response content name type"
+        "responses.201.content.application/json.schema.type" = r###""array""###, "Response content type"
+        "responses.201.content.application/json.schema.items.$ref" = r###"null"###, "Response content items ref"
+        "responses.201.content.application/json.schema.items.properties.name.type" = r###""string""###, "Response content items name type"
+    }
+}
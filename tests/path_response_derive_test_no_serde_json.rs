@@ -4,12 +4,19 @@ macro_rules! test_fn {
     ( module: $name:ident, responses: $($responses:tt)* ) => {
         #[allow(unused)]
         mod $name {
+            use crate::Foo;
+
             #[utoipa::path(get,path = "/foo",responses $($responses)*)]
             fn get_foo() {}
         }
     }
 }
 
+#[allow(unused)]
+struct Foo {
+    name: String,
+}
+
 test_fn! {
     module: response_with_string_example,
     responses: (
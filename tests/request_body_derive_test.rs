@@ -7,10 +7,8 @@ macro_rules! test_fn {
     ( module: $name:ident, body: $($body:tt)* ) => {
         #[allow(unused)]
         mod $name {
+            use crate::Foo;
 
-            struct Foo {
-                name: String,
-            }
             #[utoipa::path(
                                                 post,
                                                 path = "/foo",
@@ -24,6 +22,11 @@ macro_rules! test_fn {
     };
 }
 
+#[allow(unused)]
+struct Foo {
+    name: String,
+}
+
 test_fn! {
     module: derive_request_body_simple,
     body: = Foo
@@ -130,7 +133,7 @@ fn derive_request_body_primitive_array_success() {
         "paths./foo.post.requestBody.content.application/json" = r###"null"###, "Request body content object type not application/json"
         "paths./foo.post.requestBody.content.text/plain.schema.type" = r###""array""###, "Request body content object item type"
         "paths./foo.post.requestBody.content.text/plain.schema.items.type" = r###""integer""###, "Request body content items object type"
-        "paths./foo.post.requestBody.content.text/plain.schema.items.format" = r###""int64""###, "Request body content items object format"
+        "paths./foo.post.requestBody.content.text/plain.schema.items.format" = r###"null"###, "Request body content items object format"
         "paths./foo.post.requestBody.required" = r###"true"###, "Request body required"
         "paths./foo.post.requestBody.description" = r###"null"###, "Request body description"
     }
@@ -199,8 +202,96 @@ fn derive_request_body_complex_primitive_array_success() {
         "paths./foo.post.requestBody.content.application/json" = r###"null"###, "Request body content object type not application/json"
         "paths./foo.post.requestBody.content.text/plain.schema.type" = r###""array""###, "Request body content object item type"
         "paths./foo.post.requestBody.content.text/plain.schema.items.type" = r###""integer""###, "Request body content items object type"
-        "paths./foo.post.requestBody.content.text/plain.schema.items.format" = r###""int32""###, "Request body content items object format"
+        "paths./foo.post.requestBody.content.text/plain.schema.items.format" = r###""int64""###, "Request body content items object format"
         "paths./foo.post.requestBody.required" = r###"true"###, "Request body required"
         "paths./foo.post.requestBody.description" = r###""Create new foo references""###, "Request body description"
     }
 }
+
+mod derive_request_body_path_qualified {
+    #[allow(unused)]
+    mod models {
+        #[derive(utoipa::Component)]
+        pub struct Foo {
+            name: String,
+        }
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/foo",
+        request_body = Option<[models::Foo]>,
+        responses(
+            (status = 200, description = "success response", body = crate::derive_request_body_path_qualified::models::Foo)
+        ),
+        params(
+            ("id" = std::primitive::u64, path, description = "Foo id"),
+        )
+    )]
+    #[allow(unused)]
+    fn post_foo() {}
+}
+
+#[test]
+fn derive_path_request_body_path_qualified_type_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_request_body_path_qualified::post_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    #[cfg(not(feature = "namespaced_components"))]
+    assert_value! {doc=>
+        "paths./foo.post.requestBody.content.application/json.schema.type" = r###""array""###, "Request body content type"
+        "paths./foo.post.requestBody.content.application/json.schema.items.$ref" = r###""#/components/schemas/Foo""###, "Request body content items ref"
+        "paths./foo.post.requestBody.required" = r###"false"###, "Request body required"
+        "paths./foo.post.responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/Foo""###, "Response content ref"
+        "paths./foo.post.parameters.[0].schema.type" = r###""integer""###, "Parameter schema type"
+        "paths./foo.post.parameters.[0].schema.format" = r###"null"###, "Parameter schema format"
+    }
+
+    #[cfg(feature = "namespaced_components")]
+    assert_value! {doc=>
+        "paths./foo.post.requestBody.content.application/json.schema.items.$ref" = r###""#/components/schemas/derive_request_body_path_qualified.models.Foo""###, "Request body content items ref"
+        "paths./foo.post.responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/derive_request_body_path_qualified.models.Foo""###, "Response content ref"
+    }
+}
+
+test_fn! {
+    module: derive_request_body_bytes,
+    body: (content = [u8], content_type = "application/octet-stream")
+}
+
+#[test]
+fn derive_request_body_bytes_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_request_body_bytes::post_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths./foo.post.requestBody.content.application/octet-stream.schema.type" = r###""string""###, "Request body content type"
+        "paths./foo.post.requestBody.content.application/octet-stream.schema.format" = r###""binary""###, "Request body content format"
+        "paths./foo.post.requestBody.content.application/octet-stream.schema.items" = r###"null"###, "Request body content items"
+    }
+}
+
+test_fn! {
+    module: derive_request_body_json_bytes,
+    body: (content = [u8], content_type = "application/json")
+}
+
+#[test]
+fn derive_request_body_json_bytes_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_request_body_json_bytes::post_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(&ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths./foo.post.requestBody.content.application/json.schema.type" = r###""array""###, "Request body content type"
+        "paths./foo.post.requestBody.content.application/json.schema.items.type" = r###""integer""###, "Request body content items type"
+    }
+}
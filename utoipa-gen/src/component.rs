@@ -1,22 +1,15 @@
-use std::rc::Rc;
-
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use proc_macro_error::{abort, abort_call_site};
 use quote::{quote, ToTokens};
 use syn::{
-    punctuated::Punctuated, token::Comma, AngleBracketedGenericArguments, Attribute, Data, Field,
-    Fields, FieldsNamed, FieldsUnnamed, GenericArgument, Generics, PathArguments, PathSegment,
-    Type, TypePath, Variant,
+    punctuated::Punctuated, token::Comma, Attribute, Data, Field, Fields, FieldsNamed,
+    FieldsUnnamed, GenericArgument, Generics, Lit, Meta, PathArguments, Type, TypePath, Variant,
 };
 
-use crate::{
-    component_type::{ComponentFormat, ComponentType},
-    doc_comment::CommentAttributes,
-    Array, Deprecated,
-};
+use crate::{doc_comment::CommentAttributes, Array, Deprecated};
 
 use self::{
-    attr::{ComponentAttr, Enum, NamedField, UnnamedFieldStruct},
+    attr::{ComponentAttr, Enum, EnumVariant, NamedField, UnnamedFieldStruct},
     xml::Xml,
 };
 
@@ -42,6 +35,55 @@ impl<'a> Component<'a> {
             generics,
         }
     }
+
+    /// Implement `SplitReadWrite` for the component defined with `#[component(split_read_write)]`
+    /// attribute. Returns the `derived_components` function registering the read and write models
+    /// and the `SplitReadWrite` implementation.
+    fn split_read_write(&self) -> Option<(TokenStream2, TokenStream2)> {
+        let component = match &self.variant {
+            ComponentVariant::Named(component)
+                if attr::parse_component_attr::<ComponentAttr<attr::Struct>>(
+                    component.attributes,
+                )
+                .map(|attrs| attrs.as_ref().split_read_write)
+                .unwrap_or_default() =>
+            {
+                component
+            }
+            _ => return None,
+        };
+
+        let ident = self.ident;
+        if !self.generics.params.is_empty() {
+            abort!(
+                ident.span(),
+                "component attribute `split_read_write` is not supported for generic structs"
+            )
+        }
+
+        let (read_only, write_only) = component.read_write_only_fields();
+        let read_only = read_only.iter().collect::<Array<_>>();
+        let write_only = write_only.iter().collect::<Array<_>>();
+
+        Some((
+            quote! {
+                fn derived_components() -> Vec<(std::borrow::Cow<'static, str>, utoipa::openapi::schema::Component)> {
+                    utoipa::__private::split_read_write_components::<Self>()
+                }
+            },
+            quote! {
+                impl utoipa::SplitReadWrite for #ident {
+                    fn read_only_fields() -> &'static [&'static str] {
+                        &#read_only
+                    }
+
+                    fn write_only_fields() -> &'static [&'static str] {
+                        &#write_only
+                    }
+                }
+            },
+        ))
+    }
 }
 
 impl ToTokens for Component<'_> {
@@ -50,12 +92,47 @@ impl ToTokens for Component<'_> {
         let variant = &self.variant;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
+        let component_name = match variant.component_name() {
+            Some(component_name) => component_name,
+            None if cfg!(feature = "namespaced_components") => {
+                let ident = ident.to_string();
+                quote! {
+                    std::borrow::Cow::<'static, str>::Owned(
+                        utoipa::__private::namespaced_component_name(module_path!(), #ident)
+                    )
+                }
+            }
+            None => {
+                let ident = ident.to_string();
+                quote! { std::borrow::Cow::Borrowed(#ident) }
+            }
+        };
+        let (derived_components, split_read_write) = self.split_read_write().unwrap_or_default();
+
         tokens.extend(quote! {
             impl #impl_generics utoipa::Component for #ident #ty_generics #where_clause {
                 fn component() -> utoipa::openapi::schema::Component {
                     #variant.into()
                 }
+
+                fn component_name() -> Option<std::borrow::Cow<'static, str>> {
+                    Some(#component_name)
+                }
+
+                #derived_components
             }
+
+            impl #impl_generics utoipa::PartialComponent for #ident #ty_generics #where_clause {
+                fn partial_component() -> utoipa::openapi::schema::Component {
+                    utoipa::__private::component_ref::<Self>()
+                }
+
+                fn inline_partial_component() -> utoipa::openapi::schema::Component {
+                    <Self as utoipa::Component>::component()
+                }
+            }
+
+            #split_read_write
         })
     }
 }
@@ -105,6 +182,53 @@ impl<'a> ComponentVariant<'a> {
     }
 }
 
+impl ComponentVariant<'_> {
+    /// Custom name of the component defined with `#[component(as = ...)]` or
+    /// `#[component(remote = "...")]` attribute. Name of the remote type is resolved at runtime
+    /// the same way as references to the remote type.
+    fn component_name(&self) -> Option<TokenStream2> {
+        let (alias, remote) = match self {
+            Self::Named(component) => {
+                attr::parse_component_attr::<ComponentAttr<attr::Struct>>(component.attributes)
+                    .map(|attrs| (attrs.as_ref().alias.clone(), attrs.as_ref().remote.clone()))
+            }
+            Self::Unnamed(component) => attr::parse_component_attr::<
+                ComponentAttr<UnnamedFieldStruct>,
+            >(component.attributes)
+            .map(|attrs| (attrs.as_ref().alias.clone(), attrs.as_ref().remote.clone())),
+            Self::Enum(component) => {
+                if component.is_simple() {
+                    attr::parse_component_attr::<ComponentAttr<Enum>>(component.attributes)
+                        .map(|attrs| (attrs.as_ref().alias.clone(), attrs.as_ref().remote.clone()))
+                } else {
+                    attr::parse_component_attr::<ComponentAttr<attr::ComplexEnum>>(
+                        component.attributes,
+                    )
+                    .map(|attrs| (attrs.as_ref().alias.clone(), attrs.as_ref().remote.clone()))
+                }
+            }
+        }
+        .unwrap_or_default();
+
+        if alias.is_some() && remote.is_some() {
+            abort_call_site!(
+                "component attributes `as` and `remote` cannot be used together";
+                help = "Try removing either `as` or `remote` attribute"
+            )
+        }
+
+        match (alias, remote) {
+            (Some(alias), _) => Some(quote! { std::borrow::Cow::Borrowed(#alias) }),
+            (_, Some(remote)) => Some(quote! {
+                std::borrow::Cow::<'static, str>::Owned(
+                    utoipa::__private::type_component_name::<#remote>()
+                )
+            }),
+            (None, None) => None,
+        }
+    }
+}
+
 impl ToTokens for ComponentVariant<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match self {
@@ -121,64 +245,118 @@ struct NamedStructComponent<'a> {
     attributes: &'a [Attribute],
 }
 
+impl NamedStructComponent<'_> {
+    /// Names of the fields marked with `read_only` and `write_only` attributes.
+    fn read_write_only_fields(&self) -> (Vec<String>, Vec<String>) {
+        let mut read_only = Vec::new();
+        let mut write_only = Vec::new();
+
+        for field in self.fields {
+            let field_name = field.ident.as_ref().unwrap().to_string();
+            let attrs = attr::parse_component_attr::<ComponentAttr<NamedField>>(&field.attrs);
+
+            if matches!(&attrs, Some(attrs) if attrs.as_ref().read_only == Some(true)) {
+                read_only.push(field_name.clone());
+            }
+            if matches!(&attrs, Some(attrs) if attrs.as_ref().write_only == Some(true)) {
+                write_only.push(field_name);
+            }
+        }
+
+        (read_only, write_only)
+    }
+}
+
 impl ToTokens for NamedStructComponent<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        tokens.extend(quote! { utoipa::openapi::ObjectBuilder::new() });
+        let attrs = ComponentAttr::<attr::Struct>::from_attributes_validated(self.attributes);
+        let container_default = matches!(&attrs, Some(attrs) if attrs.as_ref().default);
+        let container_serde_default = is_serde_default(self.attributes);
+
+        let mut object = quote! { utoipa::openapi::ObjectBuilder::new() };
         self.fields.iter().for_each(|field| {
             let field_name = &*field.ident.as_ref().unwrap().to_string();
 
-            let component_part = &ComponentPart::from_type(&field.ty);
+            let field_type = &FieldType::new(&field.ty);
             let deprecated = get_deprecated(&field.attrs);
-            let attrs = ComponentAttr::<NamedField>::from_attributes_validated(
-                &field.attrs,
-                component_part,
-            );
+            let attrs = ComponentAttr::<NamedField>::from_attributes_validated(&field.attrs);
 
             let type_override = attrs
                 .as_ref()
                 .and_then(|field| field.as_ref().ty.as_ref())
-                .map(ComponentPart::from_ident);
+                .map(FieldType::from_value_type);
             let xml_value = attrs
                 .as_ref()
                 .and_then(|named_field| named_field.as_ref().xml.as_ref());
-            let comments = CommentAttributes::from_attributes(&field.attrs);
+            let description = description(
+                attrs
+                    .as_ref()
+                    .and_then(|named_field| named_field.as_ref().description.as_ref()),
+                &field.attrs,
+            );
 
             let component = ComponentProperty::new(
-                component_part,
-                Some(&comments),
+                field_type,
+                description.as_ref(),
                 attrs.as_ref(),
                 deprecated.as_ref(),
                 xml_value,
                 type_override.as_ref(),
-            );
+            )
+            .inline(matches!(&attrs, Some(attrs) if attrs.as_ref().inline))
+            .container_default(container_default.then(|| {
+                quote! {
+                    defaults
+                        .as_ref()
+                        .and_then(|defaults| defaults.get(#field_name))
+                        .filter(|default| !default.is_null())
+                        .cloned()
+                }
+            }));
 
-            tokens.extend(quote! {
+            object.extend(quote! {
                 .property(#field_name, #component)
             });
 
-            if !component.is_option() {
-                tokens.extend(quote! {
+            if !component.is_option() && !container_serde_default && !is_serde_default(&field.attrs)
+            {
+                object.extend(quote! {
                     .required(#field_name)
                 })
             }
         });
 
         if let Some(deprecated) = get_deprecated(self.attributes) {
-            tokens.extend(quote! { .deprecated(Some(#deprecated)) });
+            object.extend(quote! { .deprecated(Some(#deprecated)) });
         }
 
-        let attrs = ComponentAttr::<attr::Struct>::from_attributes_validated(self.attributes);
-        if let Some(attrs) = attrs {
-            tokens.extend(attrs.to_token_stream());
+        if let Some(ref attrs) = attrs {
+            object.extend(attrs.to_token_stream());
         }
 
-        if let Some(comment) = CommentAttributes::from_attributes(self.attributes)
-            .0
-            .first()
-        {
+        if let Some(description) = description(
+            attrs
+                .as_ref()
+                .and_then(|attrs| attrs.as_ref().description.as_ref()),
+            self.attributes,
+        ) {
+            object.extend(quote! {
+                .description(Some(#description))
+            })
+        }
+
+        if container_default {
+            // default values of the fields are resolved from the serialized `Default` value,
+            // `null` values e.g. of `Option::None` are not documented as defaults and if the
+            // value cannot be serialized the fields have no defaults
             tokens.extend(quote! {
-                .description(Some(#comment))
+                {
+                    let defaults = utoipa::__private::serialize_default::<Self>();
+                    #object
+                }
             })
+        } else {
+            tokens.extend(object)
         }
     }
 }
@@ -191,29 +369,27 @@ struct UnnamedStructComponent<'a> {
 
 impl ToTokens for UnnamedStructComponent<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let fields_len = self.fields.len();
-        let first_field = self.fields.first().unwrap();
-        let first_part = &ComponentPart::from_type(&first_field.ty);
-
-        let all_fields_are_same = fields_len == 1
-            || self.fields.iter().skip(1).all(|field| {
-                let component_part = &ComponentPart::from_type(&field.ty);
-
-                first_part == component_part
-            });
-
         let attrs =
             attr::parse_component_attr::<ComponentAttr<UnnamedFieldStruct>>(self.attributes);
         let deprecated = get_deprecated(self.attributes);
-        if all_fields_are_same {
-            let type_override = attrs
+        let description = description(
+            attrs
                 .as_ref()
-                .and_then(|unnamed_struct| unnamed_struct.as_ref().ty.as_ref())
-                .map(ComponentPart::from_ident);
+                .and_then(|unnamed_struct| unnamed_struct.as_ref().description.as_ref()),
+            self.attributes,
+        );
+        let value_type = attrs
+            .as_ref()
+            .and_then(|unnamed_struct| unnamed_struct.as_ref().ty.as_ref());
+
+        if self.fields.len() == 1 {
+            let field_type = FieldType::new(&self.fields[0].ty);
+            let type_override = value_type.map(FieldType::from_value_type);
+
             tokens.extend(
                 ComponentProperty::new(
-                    first_part,
-                    None,
+                    &field_type,
+                    description.as_ref(),
                     attrs.as_ref(),
                     deprecated.as_ref(),
                     None,
@@ -224,34 +400,30 @@ impl ToTokens for UnnamedStructComponent<'_> {
         } else {
             // Struct that has multiple unnamed fields is serialized to array by default with serde.
             // See: https://serde.rs/json.html
-            // Typically OpenAPI does not support multi type arrays thus we simply consider the case
-            // as generic object array
-            tokens.extend(quote! {
-                utoipa::openapi::ObjectBuilder::new()
-            });
-
-            if let Some(deprecated) = deprecated {
-                tokens.extend(quote! { .deprecated(Some(#deprecated)) });
-            }
-
-            if let Some(attrs) = attrs {
-                tokens.extend(attrs.to_token_stream())
-            }
-        };
+            // It is documented as tuple of the field types or of the `value_type` override.
+            let types = self
+                .fields
+                .iter()
+                .map(|field| value_type.unwrap_or(&field.ty));
+            let tuple: Type = syn::parse_quote! { (#(#types),*) };
+            let field_type = FieldType::new(&tuple);
 
-        if let Some(comment) = CommentAttributes::from_attributes(self.attributes)
-            .0
-            .first()
-        {
-            tokens.extend(quote! {
-                .description(Some(#comment))
-            })
-        }
-
-        if fields_len > 1 {
             tokens.extend(
-                quote! { .to_array_builder().max_items(Some(#fields_len)).min_items(Some(#fields_len)) },
-            )
+                ComponentProperty::new(
+                    &field_type,
+                    description.as_ref(),
+                    attrs.as_ref(),
+                    deprecated.as_ref(),
+                    None,
+                    None,
+                )
+                .title(
+                    attrs
+                        .as_ref()
+                        .and_then(|attrs| attrs.as_ref().alias.as_ref()),
+                )
+                .to_token_stream(),
+            );
         }
     }
 }
@@ -262,13 +434,18 @@ struct EnumComponent<'a> {
     attributes: &'a [Attribute],
 }
 
-impl ToTokens for EnumComponent<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream2) {
-        if self
-            .variants
+impl EnumComponent<'_> {
+    /// Check whether enum consists only of unit variants.
+    fn is_simple(&self) -> bool {
+        self.variants
             .iter()
             .all(|variant| matches!(variant.fields, Fields::Unit))
-        {
+    }
+}
+
+impl ToTokens for EnumComponent<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        if self.is_simple() {
             tokens.extend(
                 SimpleEnum {
                     attributes: self.attributes,
@@ -294,41 +471,312 @@ struct SimpleEnum<'a> {
     attributes: &'a [Attribute],
 }
 
-impl ToTokens for SimpleEnum<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let enum_values = self
-            .variants
+impl SimpleEnum<'_> {
+    /// Unit variants of the enum.
+    fn unit_variants(&self) -> impl Iterator<Item = &Variant> {
+        self.variants
             .iter()
             .filter(|variant| matches!(variant.fields, Fields::Unit))
-            .map(|variant| variant.ident.to_string())
-            .collect::<Array<String>>();
+    }
 
-        tokens.extend(quote! {
-            utoipa::openapi::PropertyBuilder::new()
-            .component_type(utoipa::openapi::ComponentType::String)
-            .enum_values(Some(#enum_values))
-        });
+    /// Descriptions of the variants from their doc comments or `None` if none of the variants
+    /// is documented.
+    fn variant_descriptions(&self) -> Option<Array<String>> {
+        let descriptions = self
+            .unit_variants()
+            .map(|variant| {
+                CommentAttributes::from_attributes(&variant.attrs)
+                    .as_formatted_string()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        if descriptions
+            .iter()
+            .any(|description| !description.is_empty())
+        {
+            Some(descriptions.into_iter().collect())
+        } else {
+            None
+        }
+    }
+
+    /// Integer type of `#[repr(...)]` attribute of an enum serialized as integers with `serde_repr`.
+    /// Serialization with `serde_repr` is either declared with `#[component(repr)]` or detected from
+    /// `Serialize_repr` derive visible to the `Component` derive.
+    fn repr_type(&self) -> Option<Ident> {
+        let is_repr = attr::parse_component_attr::<ComponentAttr<Enum>>(self.attributes)
+            .map(|attrs| attrs.as_ref().repr)
+            .unwrap_or(false);
+
+        if !is_repr && !self.derives_serialize_repr() {
+            return None;
+        }
+
+        let repr_type = self
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.path.is_ident("repr"))
+            .find_map(|attribute| {
+                attribute
+                    .parse_args_with(Punctuated::<Ident, Comma>::parse_terminated)
+                    .ok()?
+                    .into_iter()
+                    .find(|ident| {
+                        matches!(
+                            &*ident.to_string(),
+                            "u8" | "u16"
+                                | "u32"
+                                | "u64"
+                                | "u128"
+                                | "usize"
+                                | "i8"
+                                | "i16"
+                                | "i32"
+                                | "i64"
+                                | "i128"
+                                | "isize"
+                        )
+                    })
+            });
+
+        if repr_type.is_none() && is_repr {
+            abort_call_site!("`repr` requires integer representation of the enum e.g. #[repr(u8)]")
+        }
+
+        repr_type
+    }
+
+    /// Check whether `Serialize_repr` of `serde_repr` is derived in a `#[derive(...)]` attribute
+    /// which is still present on the enum. Derives listed together with `Component` are not visible.
+    fn derives_serialize_repr(&self) -> bool {
+        self.attributes
+            .iter()
+            .filter(|attribute| attribute.path.is_ident("derive"))
+            .filter_map(|attribute| {
+                attribute
+                    .parse_args_with(Punctuated::<syn::Path, Comma>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .any(|path| matches!(path.segments.last(), Some(segment) if segment.ident == "Serialize_repr"))
+    }
 
+    /// Values of the unit variants. Value is either given with `#[component(value = ...)]`,
+    /// the discriminant of the variant if enum has integer representation or the name of the
+    /// variant.
+    fn variant_values(&self) -> Vec<EnumValue> {
+        let repr_type = self.repr_type();
+
+        self.unit_variants()
+            .map(|variant| {
+                let value =
+                    attr::parse_component_attr::<ComponentAttr<EnumVariant>>(&variant.attrs)
+                        .and_then(|attrs| attrs.as_ref().value.clone());
+
+                match (value, &repr_type) {
+                    (Some(value), _) => EnumValue::from_lit(value),
+                    (None, Some(repr_type)) if !cfg!(feature = "json") => abort!(
+                        repr_type,
+                        "integer enum values require json feature to be enabled"
+                    ),
+                    (None, Some(repr_type)) => {
+                        let ident = &variant.ident;
+                        EnumValue {
+                            value: quote! { Self::#ident as #repr_type },
+                            value_type: EnumValueType::Integer,
+                        }
+                    }
+                    (None, None) => EnumValue {
+                        value: variant.ident.to_string().to_token_stream(),
+                        value_type: EnumValueType::String,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Each variant is documented as single value schema of `oneOf` with its own description
+    /// and deprecation.
+    fn one_of_to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(quote! { utoipa::openapi::OneOfBuilder::new() });
+
+        for (variant, value) in self.unit_variants().zip(self.variant_values()) {
+            let values = EnumValues(std::slice::from_ref(&value));
+            let component_type = &value.value_type;
+            let mut item = quote! {
+                utoipa::openapi::PropertyBuilder::new()
+                    .component_type(#component_type)
+                    .enum_values(Some(#values))
+            };
+
+            if let Some(description) = description(None, &variant.attrs) {
+                item.extend(quote! { .description(Some(#description)) })
+            }
+
+            if let Some(deprecated) = get_deprecated(&variant.attrs) {
+                item.extend(quote! { .deprecated(Some(#deprecated)) })
+            }
+
+            tokens.extend(quote! { .item(#item) })
+        }
+    }
+}
+
+impl ToTokens for SimpleEnum<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
         let attrs = attr::parse_component_attr::<ComponentAttr<Enum>>(self.attributes);
-        if let Some(attributes) = attrs {
-            tokens.extend(attributes.to_token_stream());
+
+        let variant_values = self.variant_values();
+        let enum_values = EnumValues(&variant_values);
+        let component_type = enum_values.component_type();
+
+        // values of different types are documented as `oneOf` where each value has its own type
+        if component_type.is_none() || matches!(&attrs, Some(attrs) if attrs.as_ref().one_of) {
+            self.one_of_to_tokens(tokens);
+        } else {
+            tokens.extend(quote! {
+                utoipa::openapi::PropertyBuilder::new()
+                #component_type
+                .enum_values(Some(#enum_values))
+            });
+
+            if let Some(descriptions) = self.variant_descriptions() {
+                let names = self
+                    .unit_variants()
+                    .map(|variant| variant.ident.to_string())
+                    .collect::<Array<String>>();
+
+                tokens.extend(quote! {
+                    .enum_varnames(Some(#names))
+                    .enum_descriptions(Some(#descriptions))
+                })
+            }
         }
 
         if let Some(deprecated) = get_deprecated(self.attributes) {
             tokens.extend(quote! { .deprecated(Some(#deprecated)) });
         }
 
-        if let Some(comment) = CommentAttributes::from_attributes(self.attributes)
-            .0
-            .first()
-        {
+        if let Some(ref attributes) = attrs {
+            tokens.extend(attributes.to_token_stream());
+        }
+
+        if let Some(description) = description(
+            attrs
+                .as_ref()
+                .and_then(|attrs| attrs.as_ref().description.as_ref()),
+            self.attributes,
+        ) {
             tokens.extend(quote! {
-                .description(Some(#comment))
+                .description(Some(#description))
             })
         }
     }
 }
 
+/// Type of the value of a simple enum variant.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum EnumValueType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+}
+
+impl ToTokens for EnumValueType {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Self::String => quote! { utoipa::openapi::ComponentType::String },
+            Self::Integer => quote! { utoipa::openapi::ComponentType::Integer },
+            Self::Number => quote! { utoipa::openapi::ComponentType::Number },
+            Self::Boolean => quote! { utoipa::openapi::ComponentType::Boolean },
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct EnumValue {
+    value: TokenStream2,
+    value_type: EnumValueType,
+}
+
+impl EnumValue {
+    fn from_lit(lit: Lit) -> Self {
+        let value_type = match &lit {
+            Lit::Str(_) => EnumValueType::String,
+            Lit::Int(_) => EnumValueType::Integer,
+            Lit::Float(_) => EnumValueType::Number,
+            Lit::Bool(_) => EnumValueType::Boolean,
+            _ => abort!(
+                lit,
+                "unexpected literal, expected string, integer, float or boolean literal"
+            ),
+        };
+
+        if value_type != EnumValueType::String && !cfg!(feature = "json") {
+            abort!(
+                lit,
+                "enum values other than strings require json feature to be enabled"
+            )
+        }
+
+        Self {
+            value: lit.into_token_stream(),
+            value_type,
+        }
+    }
+}
+
+/// Tokenizes enum values as array. Values of enums having other than string values are
+/// converted to json values which requires **json** feature.
+struct EnumValues<'a>(&'a [EnumValue]);
+
+impl EnumValues<'_> {
+    fn is_string(&self) -> bool {
+        self.0
+            .iter()
+            .all(|value| value.value_type == EnumValueType::String)
+    }
+
+    /// Tokenize builder call setting type of the values. Returns `None` for mixed value types.
+    fn component_type(&self) -> Option<TokenStream2> {
+        match self.0.first() {
+            Some(first)
+                if self
+                    .0
+                    .iter()
+                    .all(|value| value.value_type == first.value_type) =>
+            {
+                let value_type = first.value_type;
+                Some(quote! { .component_type(#value_type) })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for EnumValues<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let is_string = self.is_string();
+
+        tokens.extend(
+            self.0
+                .iter()
+                .map(|EnumValue { value, .. }| {
+                    if is_string {
+                        value.clone()
+                    } else {
+                        quote! { serde_json::json!(#value) }
+                    }
+                })
+                .collect::<Array<_>>()
+                .to_token_stream(),
+        )
+    }
+}
+
 struct ComplexEnum<'a> {
     variants: &'a Punctuated<Variant, Comma>,
     attributes: &'a [Attribute],
@@ -336,18 +784,7 @@ struct ComplexEnum<'a> {
 
 impl ToTokens for ComplexEnum<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        if self
-            .attributes
-            .iter()
-            .any(|attribute| attribute.path.get_ident().unwrap() == "component")
-        {
-            abort!(
-                self.attributes.first().unwrap(),
-                "component macro attribute not expected on complex enum";
-
-                help = "Try adding the #[component(...)] on variant of the enum";
-            );
-        }
+        let attrs = attr::parse_component_attr::<ComponentAttr<attr::ComplexEnum>>(self.attributes);
 
         let capasity = self.variants.len();
         tokens.extend(quote! {
@@ -383,11 +820,25 @@ impl ToTokens for ComplexEnum<'_> {
                     }
                 }
                 Fields::Unit => {
+                    // doc comments of the variant are used as the description of the
+                    // single value enum so they are not repeated as variant descriptions
+                    let mut unit_variant = variant.clone();
+                    unit_variant
+                        .attrs
+                        .retain(|attribute| !attribute.path.is_ident("doc"));
+                    // component attributes of the variant are variant level attributes
+                    let attributes = variant
+                        .attrs
+                        .iter()
+                        .filter(|attribute| !attribute.path.is_ident("component"))
+                        .cloned()
+                        .collect::<Vec<_>>();
+
                     let mut enum_values = Punctuated::<Variant, Comma>::new();
-                    enum_values.push(variant.clone());
+                    enum_values.push(unit_variant);
 
                     SimpleEnum {
-                        attributes: &variant.attrs,
+                        attributes: &attributes,
                         variants: &enum_values,
                     }
                     .to_token_stream()
@@ -399,17 +850,55 @@ impl ToTokens for ComplexEnum<'_> {
                 })
             });
 
-        if let Some(comment) = CommentAttributes::from_attributes(self.attributes)
-            .0
-            .first()
+        if let Some(alias) = attrs
+            .as_ref()
+            .and_then(|attrs| attrs.as_ref().alias.as_ref())
         {
             tokens.extend(quote! {
-                .description(Some(#comment))
+                .title(Some(#alias))
+            })
+        }
+
+        if let Some(description) = description(
+            attrs
+                .as_ref()
+                .and_then(|attrs| attrs.as_ref().description.as_ref()),
+            self.attributes,
+        ) {
+            tokens.extend(quote! {
+                .description(Some(#description))
             })
         }
     }
 }
 
+/// Resolve description given with `description = ...` attribute falling back to the doc
+/// comments of the item.
+fn description(
+    description: Option<&TokenStream2>,
+    attributes: &[Attribute],
+) -> Option<TokenStream2> {
+    description.cloned().or_else(|| {
+        CommentAttributes::from_attributes(attributes)
+            .as_formatted_string()
+            .map(|comment| comment.into_token_stream())
+    })
+}
+
+/// Check whether `#[serde(default)]` or `#[serde(default = "...")]` is defined in the attributes.
+fn is_serde_default(attributes: &[Attribute]) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("serde"))
+        .filter_map(|attribute| {
+            attribute
+                .parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("default"))
+}
+
 fn get_deprecated(attributes: &[Attribute]) -> Option<Deprecated> {
     attributes.iter().find_map(|attribute| {
         if *attribute.path.get_ident().unwrap() == "deprecated" {
@@ -420,182 +909,167 @@ fn get_deprecated(attributes: &[Attribute]) -> Option<Deprecated> {
     })
 }
 
-#[derive(PartialEq)]
+/// Type of a field or of the `value_type = ...` override. Only `Option` is recognized from the
+/// syntax of the type since it defines whether the field is required. The component of the type
+/// is resolved with the `PartialComponent` implementation of the type.
 #[cfg_attr(feature = "debug", derive(Debug))]
-/// Linked list of implementing types of a field in a struct.
-struct ComponentPart<'a> {
-    ident: &'a Ident,
-    value_type: ValueType,
-    generic_type: Option<GenericType>,
-    child: Option<Rc<ComponentPart<'a>>>,
-}
-
-impl<'a> ComponentPart<'a> {
-    fn from_type(ty: &'a Type) -> ComponentPart<'a> {
-        ComponentPart::from_type_path(
-            match ty {
-                Type::Path(path) => path,
-                Type::Reference(reference) => match reference.elem.as_ref() {
-                    Type::Path(path) => path,
-                    _ => abort_call_site!("unexpected type in reference, expected Type:Path"),
+struct FieldType<'a> {
+    ty: &'a Type,
+    is_option: bool,
+    /// Whether the type is `value_type = Object` which is documented as generic object without
+    /// properties.
+    is_object: bool,
+}
+
+impl<'a> FieldType<'a> {
+    fn new(ty: &'a Type) -> Self {
+        match ty {
+            Type::Reference(reference) => FieldType::new(&reference.elem),
+            Type::Paren(paren) => FieldType::new(&paren.elem),
+            Type::Group(group) => FieldType::new(&group.elem),
+            Type::Path(path) => match option_type(path) {
+                Some(ty) => FieldType {
+                    is_option: true,
+                    ..FieldType::new(ty)
+                },
+                None => FieldType {
+                    ty,
+                    is_option: false,
+                    is_object: false,
                 },
-                _ => abort_call_site!("unexpected type, expected Type::Path"),
             },
-            ComponentPart::convert,
-            ComponentPart::resolve_component_type,
-        )
-    }
-
-    fn from_ident(ty: &'a Ident) -> ComponentPart<'a> {
-        ComponentPart {
-            child: None,
-            generic_type: None,
-            ident: ty,
-            value_type: if ComponentType(ty).is_primitive() {
-                ValueType::Primitive
-            } else {
-                ValueType::Object
+            _ => FieldType {
+                ty,
+                is_option: false,
+                is_object: false,
             },
         }
     }
 
-    fn from_type_path(
-        type_path: &'a TypePath,
-        op: impl Fn(&'a Ident, &'a PathSegment) -> ComponentPart<'a>,
-        or_else: impl Fn(&'a PathSegment) -> ComponentPart<'a>,
-    ) -> ComponentPart<'a> {
-        let segment = type_path.path.segments.first().unwrap();
+    /// Resolve type given with `value_type = ...` attribute. Plain `Object` is resolved as
+    /// generic object without properties.
+    fn from_value_type(ty: &'a Type) -> Self {
+        let mut field_type = FieldType::new(ty);
 
-        type_path
-            .path
-            .get_ident()
-            .map(|ident| op(ident, segment))
-            .unwrap_or_else(|| or_else(segment))
-    }
-
-    // Only when type is a generic type we get to this function.
-    fn resolve_component_type(segment: &'a PathSegment) -> ComponentPart<'a> {
-        if segment.arguments.is_empty() {
-            abort!(
-                segment.ident,
-                "expected at least one angle bracket argument but was 0"
-            );
-        };
-
-        let mut generic_component_type = ComponentPart::convert(&segment.ident, segment);
-
-        generic_component_type.child = Some(Rc::new(ComponentPart::from_type(
-            match &segment.arguments {
-                PathArguments::AngleBracketed(angle_bracketed_args) => {
-                    ComponentPart::get_generic_arg_type(0, angle_bracketed_args)
-                }
-                _ => abort!(
-                    segment.ident,
-                    "unexpected path argument, expected angle bracketed path argument"
-                ),
-            },
-        )));
-
-        generic_component_type
-    }
-
-    fn get_generic_arg_type(index: usize, args: &'a AngleBracketedGenericArguments) -> &'a Type {
-        let generic_arg = args.args.iter().nth(index);
-
-        match generic_arg {
-            Some(GenericArgument::Type(generic_type)) => generic_type,
-            Some(GenericArgument::Lifetime(_)) => {
-                ComponentPart::get_generic_arg_type(index + 1, args)
-            }
-            _ => abort!(
-                generic_arg,
-                "expected generic argument type or generic argument lifetime"
-            ),
+        if matches!(field_type.ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("Object"))
+        {
+            field_type.is_object = true;
         }
-    }
 
-    fn convert(ident: &'a Ident, segment: &PathSegment) -> ComponentPart<'a> {
-        let generic_type = ComponentPart::get_generic(segment);
+        field_type
+    }
+}
 
-        Self {
-            ident,
-            value_type: if ComponentType(ident).is_primitive() {
-                ValueType::Primitive
-            } else {
-                ValueType::Object
-            },
-            generic_type,
-            child: None,
-        }
+/// Get the type argument of `Option<T>` type.
+fn option_type(type_path: &TypePath) -> Option<&Type> {
+    let segment = type_path.path.segments.last()?;
+    if type_path.qself.is_some() || segment.ident != "Option" {
+        return None;
     }
 
-    fn get_generic(segment: &PathSegment) -> Option<GenericType> {
-        match &*segment.ident.to_string() {
-            "HashMap" | "Map" | "BTreeMap" => Some(GenericType::Map),
-            "Vec" => Some(GenericType::Vec),
-            "Option" => Some(GenericType::Option),
-            "Cow" => Some(GenericType::Cow),
-            "Box" => Some(GenericType::Box),
-            "RefCell" => Some(GenericType::RefCell),
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
             _ => None,
-        }
+        }),
+        _ => None,
     }
 }
 
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(Clone, Copy, PartialEq)]
-enum ValueType {
-    Primitive,
-    Object,
-}
-
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(PartialEq, Clone, Copy)]
-enum GenericType {
-    Vec,
-    Map,
-    Option,
-    Cow,
-    Box,
-    RefCell,
-}
-
-#[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(PartialEq)]
-struct TypeTuple<'a, T>(T, &'a Ident);
-
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct ComponentProperty<'a, T> {
-    component_part: &'a ComponentPart<'a>,
-    comments: Option<&'a CommentAttributes>,
+    field_type: &'a FieldType<'a>,
+    description: Option<&'a TokenStream2>,
     attrs: Option<&'a ComponentAttr<T>>,
     deprecated: Option<&'a Deprecated>,
     xml: Option<&'a Xml>,
-    type_override: Option<&'a ComponentPart<'a>>,
+    type_override: Option<&'a FieldType<'a>>,
+    inline: bool,
+    container_default: Option<TokenStream2>,
+    title: Option<&'a String>,
 }
 
 impl<'a, T: Sized + ToTokens> ComponentProperty<'a, T> {
     fn new(
-        component_part: &'a ComponentPart<'a>,
-        comments: Option<&'a CommentAttributes>,
+        field_type: &'a FieldType<'a>,
+        description: Option<&'a TokenStream2>,
         attrs: Option<&'a ComponentAttr<T>>,
         deprecated: Option<&'a Deprecated>,
         xml: Option<&'a Xml>,
-        type_override: Option<&'a ComponentPart<'a>>,
+        type_override: Option<&'a FieldType<'a>>,
     ) -> Self {
         Self {
-            component_part,
-            comments,
+            field_type,
+            description,
             attrs,
             deprecated,
             xml,
             type_override,
+            inline: false,
+            container_default: None,
+            title: None,
         }
     }
 
-    /// Check wheter property is required or not
+    /// Set whether object component is inlined in place instead of being referenced.
+    fn inline(mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Set default value resolved from the `Default` implementation of the container.
+    fn container_default(mut self, container_default: Option<TokenStream2>) -> Self {
+        self.container_default = container_default;
+        self
+    }
+
+    /// Set title of the component.
+    fn title(mut self, title: Option<&'a String>) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Check wheter property is required or not. Property is not required if either the actual
+    /// type or the `value_type` override is `Option`.
     fn is_option(&self) -> bool {
-        matches!(self.component_part.generic_type, Some(GenericType::Option))
+        self.field_type.is_option
+            || matches!(self.type_override, Some(type_override) if type_override.is_option)
+    }
+
+    /// Tokenize the attributes of the field as `FieldAttributes` builder calls.
+    fn attributes_to_tokens(&self, tokens: &mut TokenStream2) {
+        if let Some(ref container_default) = self.container_default {
+            tokens.extend(quote! { .default(#container_default) })
+        }
+
+        if let Some(title) = self.title {
+            tokens.extend(quote! { .title(Some(#title)) })
+        }
+
+        if let Some(description) = self.description {
+            tokens.extend(quote! {
+                .description(Some(#description))
+            })
+        }
+
+        if let Some(deprecated) = self.deprecated {
+            tokens.extend(quote! { .deprecated(Some(#deprecated)) });
+        }
+
+        if let Some(attributes) = self.attrs {
+            tokens.extend(attributes.to_token_stream())
+        }
+
+        match self.xml {
+            Some(Xml::NonSlice(xml)) => tokens.extend(quote! {
+                .xml(Some(#xml))
+            }),
+            Some(Xml::Slice { vec, value }) => tokens.extend(quote! {
+                .xml(Some(#value))
+                .wrapper_xml(Some(#vec))
+            }),
+            None => (),
+        }
     }
 }
 
@@ -604,116 +1078,30 @@ where
     T: Sized + quote::ToTokens,
 {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        match self.component_part.generic_type {
-            Some(GenericType::Map) => {
-                // Maps are treated just as generic objects without types. There is no Map type in OpenAPI spec.
-                tokens.extend(quote! {
-                    utoipa::openapi::ObjectBuilder::new()
-                });
+        // value_type override is resolved the same way as the actual type of the field
+        let field_type = self.type_override.unwrap_or(self.field_type);
 
-                if let Some(description) = self.comments.and_then(|attributes| attributes.0.first())
-                {
-                    tokens.extend(quote! {
-                        .description(Some(#description))
-                    })
-                }
-            }
-            Some(GenericType::Vec) => {
-                let component_property = ComponentProperty::new(
-                    self.component_part.child.as_ref().unwrap(),
-                    self.comments,
-                    self.attrs,
-                    self.deprecated,
-                    self.xml,
-                    self.type_override,
-                );
-
-                if self.type_override.is_none() {
-                    tokens.extend(quote! {
-                        #component_property.to_array_builder()
-                    });
-
-                    if let Some(xml_value) = self.xml {
-                        match xml_value {
-                            Xml::Slice { vec, value: _ } => tokens.extend(quote! {
-                                .xml(Some(#vec))
-                            }),
-                            Xml::NonSlice(_) => (),
-                        }
-                    }
-                } else {
-                    tokens.extend(quote! { #component_property })
-                }
-            }
-            Some(GenericType::Option)
-            | Some(GenericType::Cow)
-            | Some(GenericType::Box)
-            | Some(GenericType::RefCell) => {
-                let component_property = ComponentProperty::new(
-                    self.component_part.child.as_ref().unwrap(),
-                    self.comments,
-                    self.attrs,
-                    self.deprecated,
-                    self.xml,
-                    self.type_override,
-                );
-
-                tokens.extend(component_property.into_token_stream())
+        let component = if field_type.is_object {
+            quote! {
+                utoipa::openapi::schema::Component::from(utoipa::openapi::ObjectBuilder::new())
             }
-            None => {
-                let component_part = self.type_override.unwrap_or(self.component_part);
-
-                match component_part.value_type {
-                    ValueType::Primitive => {
-                        let component_type = ComponentType(component_part.ident);
-
-                        tokens.extend(quote! {
-                            utoipa::openapi::PropertyBuilder::new().component_type(#component_type)
-                        });
-
-                        let format = ComponentFormat(component_part.ident);
-                        if format.is_known_format() {
-                            tokens.extend(quote! {
-                                .format(Some(#format))
-                            })
-                        }
-
-                        if let Some(description) =
-                            self.comments.and_then(|attributes| attributes.0.first())
-                        {
-                            tokens.extend(quote! {
-                                .description(Some(#description))
-                            })
-                        }
-
-                        if let Some(deprecated) = self.deprecated {
-                            tokens.extend(quote! { .deprecated(Some(#deprecated)) });
-                        }
-
-                        if let Some(attributes) = self.attrs {
-                            tokens.extend(attributes.to_token_stream())
-                        }
+        } else if self.inline {
+            crate::resolve_inline_component(field_type.ty)
+        } else {
+            crate::resolve_partial_component(field_type.ty)
+        };
 
-                        if let Some(xml_value) = self.xml {
-                            match xml_value {
-                                Xml::Slice { vec: _, value } => tokens.extend(quote! {
-                                    .xml(Some(#value))
-                                }),
-                                Xml::NonSlice(xml) => tokens.extend(quote! {
-                                    .xml(Some(#xml))
-                                }),
-                            }
-                        }
-                    }
-                    ValueType::Object => {
-                        let name = &*self.component_part.ident.to_string();
+        let mut attributes = TokenStream2::new();
+        self.attributes_to_tokens(&mut attributes);
 
-                        tokens.extend(quote! {
-                            utoipa::openapi::Ref::from_component_name(#name)
-                        })
-                    }
-                }
-            }
+        if attributes.is_empty() {
+            tokens.extend(component)
+        } else {
+            tokens.extend(quote! {
+                utoipa::__private::FieldAttributes::new()
+                    #attributes
+                    .apply(#component)
+            })
         }
     }
 }
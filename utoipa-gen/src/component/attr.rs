@@ -4,17 +4,15 @@ use proc_macro2::{Ident, TokenStream};
 use proc_macro_error::{abort, ResultExt};
 use quote::{quote, ToTokens};
 use syn::{
+    ext::IdentExt,
     parenthesized,
     parse::{Parse, ParseBuffer},
-    Attribute, Error, ExprPath, Lit, Token,
+    Attribute, Error, Expr, ExprPath, Lit, LitStr, Token,
 };
 
 use crate::{parse_utils, Example};
 
-use super::{
-    xml::{Xml, XmlAttr},
-    ComponentPart,
-};
+use super::xml::{Xml, XmlAttr};
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ComponentAttr<T>
@@ -38,6 +36,26 @@ where
 pub struct Enum {
     default: Option<TokenStream>,
     example: Option<TokenStream>,
+    pub(super) description: Option<TokenStream>,
+    pub(super) one_of: bool,
+    pub(super) repr: bool,
+    pub(super) alias: Option<String>,
+    pub(super) remote: Option<syn::Path>,
+}
+
+/// Attributes of a unit variant of a simple enum.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct EnumVariant {
+    pub(super) value: Option<Lit>,
+}
+
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ComplexEnum {
+    pub(super) description: Option<TokenStream>,
+    pub(super) alias: Option<String>,
+    pub(super) remote: Option<syn::Path>,
 }
 
 #[derive(Default)]
@@ -45,13 +63,21 @@ pub struct Enum {
 pub struct Struct {
     example: Option<Example>,
     xml_attr: Option<XmlAttr>,
+    pub(super) description: Option<TokenStream>,
+    pub(super) default: bool,
+    pub(super) alias: Option<String>,
+    pub(super) remote: Option<syn::Path>,
+    pub(super) split_read_write: bool,
 }
 
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct UnnamedFieldStruct {
-    pub(super) ty: Option<Ident>,
-    format: Option<ExprPath>,
+    pub(super) ty: Option<syn::Type>,
+    pub(super) description: Option<TokenStream>,
+    pub(super) alias: Option<String>,
+    pub(super) remote: Option<syn::Path>,
+    format: Option<TokenStream>,
     default: Option<TokenStream>,
     example: Option<TokenStream>,
 }
@@ -60,23 +86,25 @@ pub struct UnnamedFieldStruct {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct NamedField {
     example: Option<TokenStream>,
-    pub(super) ty: Option<Ident>,
-    format: Option<ExprPath>,
+    pub(super) ty: Option<syn::Type>,
+    format: Option<TokenStream>,
     default: Option<TokenStream>,
-    write_only: Option<bool>,
-    read_only: Option<bool>,
+    pub(super) write_only: Option<bool>,
+    pub(super) read_only: Option<bool>,
     xml_attr: Option<XmlAttr>,
     pub(super) xml: Option<Xml>,
+    pub(super) inline: bool,
+    pub(super) description: Option<TokenStream>,
 }
 
 impl Parse for ComponentAttr<Enum> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: default, example";
+            "unexpected attribute, expected any of: default, example, description, one_of, repr, as, remote";
         let mut enum_attr = Enum::default();
 
         while !input.is_empty() {
-            let ident = input.parse::<Ident>().map_err(|error| {
+            let ident = input.call(Ident::parse_any).map_err(|error| {
                 Error::new(
                     error.span(),
                     format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
@@ -95,6 +123,11 @@ impl Parse for ComponentAttr<Enum> {
                         parse_lit_or_fn_ref_as_token_stream(input, name)
                     }))
                 }
+                "description" => enum_attr.description = Some(parse_description(input)?),
+                "one_of" => enum_attr.one_of = parse_utils::parse_bool_or_true(input)?,
+                "repr" => enum_attr.repr = parse_utils::parse_bool_or_true(input)?,
+                "as" => enum_attr.alias = Some(parse_alias(input)?),
+                "remote" => enum_attr.remote = Some(parse_remote(input)?),
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -102,10 +135,84 @@ impl Parse for ComponentAttr<Enum> {
                 input.parse::<Token![,]>()?;
             }
         }
+
+        if enum_attr.one_of && (enum_attr.default.is_some() || enum_attr.example.is_some()) {
+            return Err(Error::new(
+                input.span(),
+                "`one_of` cannot be used together with `default` or `example` attributes",
+            ));
+        }
+
         Ok(Self { inner: enum_attr })
     }
 }
 
+impl Parse for ComponentAttr<EnumVariant> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: value";
+        let mut enum_variant = EnumVariant::default();
+
+        while !input.is_empty() {
+            let ident = input.call(Ident::parse_any).map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+            let name = &*ident.to_string();
+
+            match name {
+                "value" => {
+                    enum_variant.value =
+                        Some(parse_utils::parse_next(input, || input.parse::<Lit>())?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self {
+            inner: enum_variant,
+        })
+    }
+}
+
+impl Parse for ComponentAttr<ComplexEnum> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+            "unexpected attribute, expected any of: description, as, remote";
+        let mut complex_enum = ComplexEnum::default();
+
+        while !input.is_empty() {
+            let ident = input.call(Ident::parse_any).map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+            let name = &*ident.to_string();
+
+            match name {
+                "description" => complex_enum.description = Some(parse_description(input)?),
+                "as" => complex_enum.alias = Some(parse_alias(input)?),
+                "remote" => complex_enum.remote = Some(parse_remote(input)?),
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self {
+            inner: complex_enum,
+        })
+    }
+}
+
 impl ComponentAttr<Struct> {
     pub(super) fn from_attributes_validated(attributes: &[Attribute]) -> Option<Self> {
         parse_component_attr::<ComponentAttr<Struct>>(attributes).map(|attrs| {
@@ -128,11 +235,11 @@ impl ComponentAttr<Struct> {
 impl Parse for ComponentAttr<Struct> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: example, xml";
+            "unexpected attribute, expected any of: example, xml, description, default, as, remote, split_read_write";
         let mut struct_ = Struct::default();
 
         while !input.is_empty() {
-            let ident = input.parse::<Ident>().map_err(|error| {
+            let ident = input.call(Ident::parse_any).map_err(|error| {
                 Error::new(
                     error.span(),
                     &format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
@@ -151,6 +258,21 @@ impl Parse for ComponentAttr<Struct> {
                     parenthesized!(xml in input);
                     struct_.xml_attr = Some(xml.parse()?)
                 }
+                "description" => struct_.description = Some(parse_description(input)?),
+                "default" if cfg!(feature = "json") => {
+                    struct_.default = parse_utils::parse_bool_or_true(input)?
+                }
+                "default" => {
+                    return Err(Error::new(
+                        ident.span(),
+                        "`default` requires json feature to be enabled",
+                    ))
+                }
+                "as" => struct_.alias = Some(parse_alias(input)?),
+                "remote" => struct_.remote = Some(parse_remote(input)?),
+                "split_read_write" => {
+                    struct_.split_read_write = parse_utils::parse_bool_or_true(input)?
+                }
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -166,11 +288,11 @@ impl Parse for ComponentAttr<Struct> {
 impl Parse for ComponentAttr<UnnamedFieldStruct> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: default, example, format, value_type";
+            "unexpected attribute, expected any of: default, example, format, value_type, description, as, remote";
         let mut unnamed_struct = UnnamedFieldStruct::default();
 
         while !input.is_empty() {
-            let attribute = input.parse::<Ident>().map_err(|error| {
+            let attribute = input.call(Ident::parse_any).map_err(|error| {
                 Error::new(
                     error.span(),
                     format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
@@ -191,9 +313,13 @@ impl Parse for ComponentAttr<UnnamedFieldStruct> {
                 }
                 "format" => unnamed_struct.format = Some(parse_format(input)?),
                 "value_type" => {
-                    unnamed_struct.ty =
-                        Some(parse_utils::parse_next(input, || input.parse::<Ident>())?)
+                    unnamed_struct.ty = Some(parse_utils::parse_next(input, || {
+                        input.parse::<syn::Type>()
+                    })?)
                 }
+                "description" => unnamed_struct.description = Some(parse_description(input)?),
+                "as" => unnamed_struct.alias = Some(parse_alias(input)?),
+                "remote" => unnamed_struct.remote = Some(parse_remote(input)?),
                 _ => return Err(Error::new(attribute.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -209,61 +335,34 @@ impl Parse for ComponentAttr<UnnamedFieldStruct> {
 }
 
 impl ComponentAttr<NamedField> {
-    pub(super) fn from_attributes_validated(
-        attributes: &[Attribute],
-        component_part: &ComponentPart,
-    ) -> Option<Self> {
-        parse_component_attr::<ComponentAttr<NamedField>>(attributes)
-            .map(|attrs| {
-                is_valid_xml_attr(&attrs, component_part);
-
-                attrs
-            })
-            .map(|mut attrs| {
-                if matches!(component_part.generic_type, Some(super::GenericType::Vec)) {
-                    if let Some(ref mut xml) = attrs.inner.xml_attr {
-                        let mut value_xml = mem::take(xml);
-                        let vec_xml = XmlAttr::with_wrapped(
-                            mem::take(&mut value_xml.is_wrapped),
-                            mem::take(&mut value_xml.wrap_name),
-                        );
-
-                        attrs.inner.xml = Some(Xml::Slice {
-                            vec: vec_xml,
-                            value: value_xml,
-                        });
-                    }
-                } else if let Some(ref mut xml) = attrs.inner.xml_attr {
-                    attrs.inner.xml = Some(Xml::NonSlice(mem::take(xml)));
+    /// Parse attributes of a named field. Xml of the field is split to the xml of the array
+    /// items and the xml of the array itself when the field is `wrapped`.
+    pub(super) fn from_attributes_validated(attributes: &[Attribute]) -> Option<Self> {
+        parse_component_attr::<ComponentAttr<NamedField>>(attributes).map(|mut attrs| {
+            if let Some(mut value_xml) = attrs.inner.xml_attr.take() {
+                if value_xml.is_wrapped.is_some() {
+                    let vec_xml = XmlAttr::with_wrapped(
+                        mem::take(&mut value_xml.is_wrapped),
+                        mem::take(&mut value_xml.wrap_name),
+                    );
+
+                    attrs.inner.xml = Some(Xml::Slice {
+                        vec: vec_xml,
+                        value: value_xml,
+                    });
+                } else {
+                    attrs.inner.xml = Some(Xml::NonSlice(value_xml));
                 }
-
-                attrs
-            })
-    }
-}
-
-#[inline]
-fn is_valid_xml_attr(attrs: &ComponentAttr<NamedField>, component_part: &ComponentPart) {
-    if !matches!(
-        component_part.generic_type,
-        Some(crate::component::GenericType::Vec)
-    ) {
-        if let Some(wrapped_ident) = attrs
-            .as_ref()
-            .xml_attr
-            .as_ref()
-            .and_then(|xml| xml.is_wrapped.as_ref())
-        {
-            abort! {wrapped_ident, "cannot use `wrapped` attribute in non slice field type";
-                help = "Try removing `wrapped` attribute or make your field `Vec`"
             }
-        }
+
+            attrs
+        })
     }
 }
 
 impl Parse for ComponentAttr<NamedField> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: example, format, default, write_only, read_only, xml, value_type";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: example, format, default, write_only, read_only, xml, value_type, inline, description";
         let mut field = NamedField::default();
 
         while !input.is_empty() {
@@ -295,8 +394,12 @@ impl Parse for ComponentAttr<NamedField> {
                     field.xml_attr = Some(xml.parse()?)
                 }
                 "value_type" => {
-                    field.ty = Some(parse_utils::parse_next(input, || input.parse::<Ident>())?)
+                    field.ty = Some(parse_utils::parse_next(input, || {
+                        input.parse::<syn::Type>()
+                    })?)
                 }
+                "inline" => field.inline = parse_utils::parse_bool_or_true(input)?,
+                "description" => field.description = Some(parse_description(input)?),
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -309,24 +412,72 @@ impl Parse for ComponentAttr<NamedField> {
     }
 }
 
+/// Parse format given either as expression path e.g. `ComponentFormat::Int32` or as literal
+/// string e.g. `"email"` which is resolved to custom format.
+#[inline]
+fn parse_format(input: &ParseBuffer) -> Result<TokenStream, Error> {
+    parse_utils::parse_next(input, || {
+        if input.peek(LitStr) {
+            let format = input.parse::<LitStr>()?;
+
+            return Ok(quote! {
+                utoipa::openapi::ComponentFormat::Custom(String::from(#format))
+            });
+        }
+
+        let format = input.parse::<ExprPath>().map_err(|error| {
+            Error::new(
+                error.span(),
+                format!(
+                    "unparseable format expected expression path e.g. ComponentFormat::String or literal string e.g. \"email\", {}",
+                    error
+                ),
+            )
+        })?;
+
+        if format.path.segments.first().unwrap().ident != "utoipa" {
+            Ok(quote! { utoipa::openapi::#format })
+        } else {
+            Ok(format.into_token_stream())
+        }
+    })
+}
+
+/// Parse component name given with `as = ...` attribute. Path segments are joined with dots
+/// e.g. `as = v1::Pet` will be resolved to `v1.Pet`.
+#[inline]
+fn parse_alias(input: &ParseBuffer) -> Result<String, Error> {
+    let alias = parse_utils::parse_next(input, || input.call(syn::Path::parse_mod_style))?;
+
+    Ok(alias
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("."))
+}
+
+/// Parse description given either as literal string or as expression evaluating to `&str`
+/// e.g. `include_str!("../docs/pet.md")`.
 #[inline]
-fn parse_format(input: &ParseBuffer) -> Result<ExprPath, Error> {
-    let format = parse_utils::parse_next(input, || input.parse::<ExprPath>()).map_err(|error| {
+fn parse_description(input: &ParseBuffer) -> Result<TokenStream, Error> {
+    parse_utils::parse_next(input, || input.parse::<Expr>()).map(|expr| expr.to_token_stream())
+}
+
+/// Parse foreign type given with `remote = "..."` attribute. The component name is resolved
+/// from the foreign type at runtime the same way as references to the type are resolved.
+#[inline]
+fn parse_remote(input: &ParseBuffer) -> Result<syn::Path, Error> {
+    let remote = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+    remote.parse::<syn::Path>().map_err(|error| {
         Error::new(
-            error.span(),
+            remote.span(),
             format!(
-                "unparseable format expected expression path e.g. ComponentFormat::String, {}",
+                "unparseable remote, expected type path e.g. \"chrono::Weekday\", {}",
                 error
             ),
         )
-    })?;
-
-    if format.path.segments.first().unwrap().ident != "utoipa" {
-        let appended_path: ExprPath = syn::parse_quote!(utoipa::openapi::#format);
-        Ok(appended_path)
-    } else {
-        Ok(format)
-    }
+    })
 }
 
 #[inline]
@@ -402,11 +553,22 @@ impl ToTokens for Enum {
                 .example(Some(#example))
             })
         }
+
+        if let Some(ref alias) = self.alias {
+            tokens.extend(quote! {
+                .title(Some(#alias))
+            })
+        }
     }
 }
 
 impl ToTokens for Struct {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(ref alias) = self.alias {
+            tokens.extend(quote! {
+                .title(Some(#alias))
+            })
+        }
         if let Some(ref example) = self.example {
             tokens.extend(quote! {
                 .example(Some(#example))
@@ -450,12 +612,6 @@ impl ToTokens for NamedField {
             })
         }
 
-        if let Some(ref format) = self.format {
-            tokens.extend(quote! {
-                .format(Some(#format))
-            })
-        }
-
         if let Some(ref example) = self.example {
             tokens.extend(quote! {
                 .example(Some(#example))
@@ -473,5 +629,11 @@ impl ToTokens for NamedField {
                 .read_only(Some(#read_only))
             })
         }
+
+        if let Some(ref format) = self.format {
+            tokens.extend(quote! {
+                .format(Some(#format))
+            })
+        }
     }
 }
@@ -12,9 +12,40 @@ impl CommentAttributes {
     /// Creates new [`CommentAttributes`] instance from [`Attribute`] slice filtering out all
     /// other attributes which are not `doc` comments
     pub(crate) fn from_attributes(attributes: &[Attribute]) -> Self {
-        Self(Self::as_string_vec(
+        Self(Self::unindent(Self::as_string_vec(
             attributes.iter().filter(Self::is_doc_attribute),
-        ))
+        )))
+    }
+
+    /// Full doc comment as a markdown string. Lines are joined with line breaks so paragraphs,
+    /// lists and code blocks are preserved. Returns `None` if there are no doc comments.
+    pub(crate) fn as_formatted_string(&self) -> Option<String> {
+        let lines = &self.0;
+        let start = lines.iter().position(|line| !line.is_empty())?;
+        let end = lines.iter().rposition(|line| !line.is_empty())?;
+
+        Some(lines[start..=end].join("\n"))
+    }
+
+    /// Remove the common leading whitespace of non empty lines keeping the relative indentation
+    /// e.g. of nested lists and indented code blocks.
+    fn unindent(lines: Vec<String>) -> Vec<String> {
+        let indent = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or_default();
+
+        lines
+            .into_iter()
+            .map(|line| {
+                line.get(indent..)
+                    .unwrap_or_default()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
     }
 
     fn is_doc_attribute(attribute: &&Attribute) -> bool {
@@ -32,6 +63,13 @@ impl CommentAttributes {
         attributes
             .into_iter()
             .filter_map(Self::parse_doc_comment)
+            .flat_map(|comment| {
+                // block doc comments may span multiple lines
+                comment
+                    .split('\n')
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+            })
             .collect()
     }
 
@@ -42,7 +80,7 @@ impl CommentAttributes {
         match meta {
             Meta::NameValue(name_value) => {
                 if let Lit::Str(doc_comment) = name_value.lit {
-                    Some(doc_comment.value().trim().to_string())
+                    Some(doc_comment.value())
                 } else {
                     emit_warning!(
                         Span::call_site(),
@@ -20,15 +20,14 @@ use quote::{quote, ToTokens, TokenStreamExt};
 
 use proc_macro2::{Group, Ident, Punct, TokenStream as TokenStream2};
 use syn::{
-    bracketed,
+    bracketed, parenthesized,
     parse::{Parse, ParseBuffer, ParseStream},
     punctuated::Punctuated,
-    token::Bracket,
-    DeriveInput, ItemFn, Token,
+    token::{Bracket, Paren},
+    DeriveInput, ExprPath, ItemFn, Token,
 };
 
 mod component;
-mod component_type;
 mod doc_comment;
 mod ext;
 mod openapi;
@@ -56,39 +55,152 @@ use ext::ArgumentResolver;
 /// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
 /// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
 ///
+/// Doc comments of structs, enums, fields and variants are used as descriptions. The whole doc comment
+/// is used as markdown: lines are joined with line breaks and the common indentation is removed so
+/// paragraphs, lists and code blocks are preserved. Description can be overridden with
+/// `description = ...` attribute.
+///
+/// Field types are resolved with [`PartialComponent`][partial] trait which is implemented for Rust
+/// primitive types, collection types and wrapper types. Type aliases e.g. `type PetIds = Vec<u64>;`
+/// are documented as the aliased type.
+/// * `Vec`, `VecDeque`, `LinkedList`, slices `[T]` and `&[T]` are documented as arrays.
+/// * Fixed size arrays `[T; N]` are documented as arrays with `minItems` and `maxItems` of `N`.
+/// * `HashSet`, `BTreeSet` and `IndexSet` are documented as arrays with `uniqueItems`.
+/// * `HashMap`, `BTreeMap` and `IndexMap` are documented as objects.
+/// * `IndexMap` and `IndexSet` require **indexmap** feature.
+/// * Tuples e.g. `(i32, String)` are documented as arrays with `minItems` and `maxItems` of the tuple
+///   length and the positional types as `oneOf` items.
+/// * `Option`, `Box`, `Rc`, `Arc`, `Cow`, `RefCell`, `Cell`, `Mutex` and `RwLock` are documented
+///   as their inner type.
+///
+/// Types deriving `Component` are referenced as reusable components. Items of containers must
+/// implement [`PartialComponent`][partial] thus generic type parameters used as items must be bound
+/// with it e.g. `struct Page<T: PartialComponent> { items: Vec<T> }`. Other field types not
+/// implementing it are referenced by their name.
+///
+/// Field level attributes are applied to the documented field regardless of its type. Attributes of
+/// a field referencing another component are rendered by wrapping the reference to a single item
+/// `oneOf` since OpenAPI ignores siblings of `$ref`.
+///
+/// Fields are required unless they are `Option`s or serde's `#[serde(default)]` attribute is defined
+/// for the field or for the struct.
+///
+/// Integer types are documented with their bounds. Unsigned types have `minimum` of `0` and `NonZero`
+/// unsigned types `minimum` of `1`. `u8`, `i8`, `u16`, `i16`, `u32` and their `NonZero` counterparts
+/// also have `maximum` set to the range of the type. `u32` uses `int64` format and `u64` has no format
+/// since its values do not fit to `int64`.
+///
 /// # Struct Optional Configuration Options
-/// * `example = ...` Can be either `json!(...)` or literal string that can be parsed to json. `json!`
-///   should be something that `serde_json::json!` can parse as a `serde_json::Value`. [^json]
+/// * `example = ...` Can be either `json!(...)`, literal string that can be parsed to json or function
+///   reference e.g. `Pet::example`. `json!` should be something that `serde_json::json!` can parse as
+///   a `serde_json::Value`. The referenced function takes no arguments and returns value implementing
+///   `serde::Serialize`, typically `Self`, which is serialized as the example. [^json]
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to Structs.
+/// * `default` Can be used to populate defaults of the fields from the [`Default`] implementation of
+///   the struct. The default value is serialized with serde and the serialized values of the fields
+///   are used as defaults of primitive fields. Fields serialized as `null` e.g. `Option::None` have no
+///   default. Field level `default = ...` takes precedence. If the default value cannot be serialized
+///   e.g. when it contains a map with non string keys the fields are documented without defaults. [^json]
+/// * `description = ...` Can be literal string or expression evaluating to `&str` e.g.
+///   `include_str!("../docs/pet.md")`. Overrides the description derived from doc comments.
+/// * `as = ...` Can be used to define alternative name for the component. [^as]
+/// * `remote = "..."` Can be used to define component for a foreign type. [^remote]
+/// * `split_read_write` Can be used to derive separate write and read models of the struct. [^split]
 ///  
 /// [^json]: **json** feature need to be enabled for `json!(...)` type to work.
 ///
+/// [^split]: Derive implements [`SplitReadWrite`][split] for the struct. The models are `utoipa::Create<T>`
+///   without the `read_only` fields and `utoipa::Read<T>` without the `write_only` fields. Both models
+///   are registered together with the struct in `#[openapi(components(...))]` and can be referenced from
+///   paths e.g. `request_body = utoipa::Create<Pet>`. The names of the models are the name of the struct
+///   component suffixed with `Create` and `Read` e.g. `PetCreate` thus `as` and **namespaced_components**
+///   apply to them as well. Not supported for generic structs.
+///
 /// # Enum Optional Configuration Options
 /// * `example = ...` Can be method reference or literal value. [^json2]
 /// * `default = ...` Can be method reference or literal value. [^json2]
+/// * `description = ...` Can be literal string or expression evaluating to `&str` e.g.
+///   `include_str!("../docs/pet.md")`. Overrides the description derived from doc comments.
+/// * `one_of` Document each variant as single value schema of `oneOf` with the description and
+///   deprecation of the variant instead of one string schema with `enum` values. Cannot be used
+///   together with `default` or `example`.
+/// * `repr` Document the enum as integer enum with the discriminants of the variants as values. The
+///   enum must have integer representation e.g. `#[repr(u8)]` and is expected to be serialized as
+///   integer with [`serde_repr`](https://docs.rs/serde_repr). Requires **json** feature.
+/// * `as = ...` Can be used to define alternative name for the component. [^as]
+/// * `remote = "..."` Can be used to define component for a foreign type. [^remote]
+///
+/// Doc comments of unit variants are documented with `x-enum-varnames` and `x-enum-descriptions`
+/// vendor extensions understood by code generators if any of the variants is documented.
+///
+/// Enum values are the names of the variants by default. Enums with `repr` attribute are
+/// documented with the discriminants of the variants and the type _`integer`_. The `repr` attribute
+/// can be omitted when `Serialize_repr` is derived in a separate `#[derive(...)]` attribute after
+/// `#[derive(Component)]`. Derives listed in the same attribute are not visible to the `Component` derive.
+///
+/// # Enum Variant Optional Configuration Options
+/// * `value = ...` Literal value of the variant e.g. `value = 1` or `value = "low"` used instead of the
+///   name or the discriminant of the variant. Enums with values of different types are documented
+///   as `oneOf` of the values. Other than string values require **json** feature.
+///
+/// Complex enums only support the `description = ...`, `as = ...` and `remote = "..."` attributes at type level.
 ///
 /// # Unnamed Field Struct Optional Configuration Options
 /// * `example = ...` Can be method reference or literal value. [^json2]
 /// * `default = ...` Can be method reference or literal value. [^json2]
 /// * `format = ...` [`ComponentFormat`][format] to use for the property. By default the format is derived from
-///   the type of the property according OpenApi spec.
+///   the type of the property according OpenApi spec. Format can also be given as literal string e.g. `format = "email"`
+///   which is used as [custom format][custom_format]. `Binary` and `Byte` formats document the property as
+///   `string` regardless of the type e.g. for byte arrays which are serialized as strings. Otherwise byte
+///   arrays such as `Vec<u8>` and `[u8; N]` are documented as arrays of integers.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases the where default type does not correspond to the actual type e.g. when
 ///   any thrid-party types are used which are not components nor primitive types. With **value_type** we can enforce
-///   type used to certain type. Value type may only be [`primitive`][primitive] type or [`String`]. Generic types are not allowed.
+///   type used to certain type. Value type can be any type expression e.g. _`Pet`_, _`Vec<Pet>`_ or
+///   _`Option<String>`_ which is resolved the same way as the type of a field. _`Object`_ can be used to
+///   define generic object without properties.
+/// * `description = ...` Can be literal string or expression evaluating to `&str` e.g.
+///   `include_str!("../docs/pet.md")`. Overrides the description derived from doc comments.
+/// * `as = ...` Can be used to define alternative name for the component. [^as]
+/// * `remote = "..."` Can be used to define component for a foreign type. [^remote]
+///
+/// Unnamed field struct with multiple fields of different types is documented as tuple array with `oneOf`
+/// items of the field types and with **openapi_31** feature with `prefixItems` of the field types.
+///
+/// [^as]: The name is used as the key of the component in `components(...)` of [`OpenApi`][openapi_derive]
+///   derive and as the `title` of the component. Path segments are joined with dots e.g. `as = v1::Pet`
+///   becomes `v1.Pet`. References to the component from fields, request bodies, responses and
+///   parameters use the same name thus same named types from different modules can be told apart
+///   by giving them different names.
+///
+/// [^remote]: Similar to serde's remote derive the annotated type is a local mirror of a type defined
+///   in another crate e.g. `remote = "chrono::Weekday"`. The component is registered with the name of the
+///   foreign type thus fields, request bodies and responses using the foreign type reference the mirror
+///   component. The foreign type must be in scope where the mirror type is defined. `remote` cannot be
+///   used together with `as`.
 ///
 /// # Named Fields Optional Configuration Options
 /// * `example = ...` Can be method reference or literal value. [^json2]
 /// * `default = ...` Can be method reference or literal value. [^json2]
 /// * `format = ...` [`ComponentFormat`][format] to use for the property. By default the format is derived from
-///   the type of the property according OpenApi spec.
+///   the type of the property according OpenApi spec. Format can also be given as literal string e.g. `format = "email"`
+///   which is used as [custom format][custom_format]. `Binary` and `Byte` formats document the property as
+///   `string` regardless of the type e.g. for byte arrays which are serialized as strings. Otherwise byte
+///   arrays such as `Vec<u8>` and `[u8; N]` are documented as arrays of integers.
 /// * `write_only` Defines property is only used in **write** operations *POST,PUT,PATCH* but not in *GET*
 /// * `read_only` Defines property is only used in **read** operations *GET* but not in *POST,PUT,PATCH*
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to named fields.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases the where default type does not correspond to the actual type e.g. when
 ///   any thrid-party types are used which are not components nor primitive types. With **value_type** we can enforce
-///   type used to certain type. Value type may only be [`primitive`][primitive] type or [`String`]. Generic types are not allowed.
+///   type used to certain type. Value type can be any type expression e.g. _`Pet`_, _`Vec<Pet>`_ or
+///   _`Option<String>`_ which is resolved the same way as the type of a field. _`Object`_ can be used to
+///   define generic object without properties.
+/// * `inline` If set the component of the field type is rendered in place instead of being referenced
+///   by `$ref`. The field type must implement [`Component`][c] trait. Other field attributes such as
+///   `description` are applied to the inlined component.
+/// * `description = ...` Can be literal string or expression evaluating to `&str` e.g.
+///   `include_str!("../docs/pet.md")`. Overrides the description derived from doc comments.
 ///
 /// [^json2]: Values are converted to string if **json** feature is not enabled.
 ///
@@ -98,7 +210,8 @@ use ext::ArgumentResolver;
 /// * `xml(namespace = "...")` Will set namespace for xml element which needs to be valid uri.
 /// * `xml(prefix = "...")` Will set prefix for name.
 /// * `xml(attribute)` Will translate property to xml attribute instead of xml element.
-/// * `xml(wrapped)` Will make wrapped xml element.
+/// * `xml(wrapped)` Will make wrapped xml element. Used with array fields in which case the other xml
+///   attributes are applied to the array items.
 /// * `xml(wrapped(name = "wrap_name"))` Will override the wrapper elements name.
 ///
 /// See [`Xml`][xml] for more details.
@@ -117,6 +230,24 @@ use ext::ArgumentResolver;
 /// }
 /// ```
 ///
+/// Struct level example given as function reference returning `Self`. The example is type checked
+/// and kept in sync with the struct.
+/// ```rust
+/// # use utoipa::Component;
+/// #[derive(serde::Serialize, Component)]
+/// #[component(example = Pet::example)]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// impl Pet {
+///     fn example() -> Self {
+///         Self { id: 1, name: "bob the cat".to_string() }
+///     }
+/// }
+/// ```
+///
 /// The `component` attribute can also be placed at field level as follows.
 /// ```rust
 /// # use utoipa::Component;
@@ -188,6 +319,72 @@ use ext::ArgumentResolver;
 /// }
 /// ```
 ///
+/// Use `as` attribute to expose versioned models with custom names.
+/// ```rust
+/// # use utoipa::Component;
+/// mod v1 {
+///     # use utoipa::Component;
+///     #[derive(Component)]
+///     #[component(as = PetV1)]
+///     pub struct Pet {
+///         name: String,
+///     }
+/// }
+///
+/// mod v2 {
+///     # use utoipa::Component;
+///     #[derive(Component)]
+///     #[component(as = v2::Pet)]
+///     pub struct Pet {
+///         name: String,
+///         age: Option<i32>,
+///     }
+/// }
+/// ```
+///
+/// Use `split_read_write` to get separate models for creating and reading a resource. `Create<Pet>`
+/// documented as `PetCreate` is without the `id` and `Read<Pet>` documented as `PetRead` is without
+/// the `password`.
+/// ```rust
+/// # use utoipa::{Component, Create, Read};
+/// #[derive(Component)]
+/// #[component(split_read_write)]
+/// struct Pet {
+///     #[component(read_only)]
+///     id: u64,
+///     name: String,
+///     #[component(write_only)]
+///     password: String,
+/// }
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/pets",
+///     request_body = Create<Pet>,
+///     responses(
+///         (status = 200, description = "Pet created successfully", body = Read<Pet>)
+///     )
+/// )]
+/// fn create_pet() {}
+/// ```
+///
+/// Use `remote` attribute to define component for a type from other crate. Fields of `chrono::Weekday`
+/// type will then reference the `Weekday` component.
+/// ```rust
+/// # use utoipa::Component;
+/// # mod chrono { pub enum Weekday { Mon, Tue, Wed, Thu, Fri, Sat, Sun } }
+/// #[derive(Component)]
+/// #[component(remote = "chrono::Weekday")]
+/// enum WeekdayDef {
+///     Mon, Tue, Wed, Thu, Fri, Sat, Sun
+/// }
+///
+/// #[derive(Component)]
+/// struct Opening {
+///     day: chrono::Weekday,
+/// }
+/// ```
+///
 /// Use of Rust's own `#[deprecated]` attribute will reflect to generated OpenAPI spec.
 /// ```rust
 /// # use utoipa::Component;
@@ -214,6 +411,17 @@ use ext::ArgumentResolver;
 /// }
 /// ```
 ///
+/// Byte arrays serialized as strings e.g. with `serde_bytes` can be documented as binary strings with
+/// the format alone.
+/// ```rust
+/// # use utoipa::Component;
+/// #[derive(Component)]
+/// struct Attachment {
+///     #[component(format = ComponentFormat::Binary)]
+///     content: Vec<u8>,
+/// }
+/// ```
+///
 /// Enforce type being used in OpenAPI spec to String with `value_type` option.
 /// ```rust
 /// # use utoipa::Component;
@@ -222,10 +430,26 @@ use ext::ArgumentResolver;
 /// struct Value(i64);
 /// ```
 ///
+/// Use custom format not defined in [`ComponentFormat`][format] by giving it as literal string.
+/// ```rust
+/// # use utoipa::Component;
+/// #[derive(Component)]
+/// struct Account {
+///     #[component(format = "iban")]
+///     iban: String,
+///     #[component(format = "email")]
+///     email: String,
+/// }
+/// ```
+///
 /// [c]: trait.Component.html
+/// [partial]: trait.PartialComponent.html
+/// [split]: trait.SplitReadWrite.html
 /// [format]: openapi/schema/enum.ComponentFormat.html
 /// [binary]: openapi/schema/enum.ComponentFormat.html#variant.Binary
+/// [custom_format]: openapi/schema/enum.ComponentFormat.html#variant.Custom
 /// [xml]: openapi/xml/struct.Xml.html
+/// [openapi_derive]: derive.OpenApi.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 pub fn derive_component(input: TokenStream) -> TokenStream {
     let DeriveInput {
@@ -279,15 +503,22 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// > To define description or other parameter info then **params** still need to be defined manually. See the example
 /// > in [examples section](#examples).
 ///
+/// > **Note!** Types given to **request_body**, **body**, **params** and response **headers** are resolved
+/// > as Rust types. They must be in scope where the path operation is defined, either imported or written
+/// > with a path such as _`models::Pet`_.
+///
 /// # Request Body Attributes
 ///
-/// * `content = ...` Can be used to define the content object. Should be an identifier, slice or option
-///   E.g. _`Pet`_ or _`[Pet]`_ or _`Option<Pet>`_.
+/// * `content = ...` Can be used to define the content object. Should be an identifier, path, slice or option
+///   E.g. _`Pet`_, _`models::Pet`_, _`[Pet]`_ or _`Option<Pet>`_. Type can be wrapped with _`inline(...)`_
+///   e.g. _`inline(Pet)`_ to render the component schema in place instead of referencing it.
 /// * `description = "..."` Define the description for the request body object as str.
 /// * `content_type = "..."` Can be used to override the default behaviour of auto resolving the content type
 ///   from the `content` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
 ///   [primitive Rust types][primitive] and _`application/json`_ for struct and complex enum types.
+///   Byte slice _`[u8]`_ is documented as `binary` string when the content type is not JSON
+///   e.g. _`application/octet-stream`_. Otherwise it is documented as array of integers.
 ///
 /// **Request body supports following formats:**
 ///
@@ -306,14 +537,17 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// * `status = ...` Is valid http status code. E.g. _`200`_
 /// * `description = "..."` Define description for the response as str.
 /// * `body = ...` Optional response body object type. When left empty response does not expect to send any
-///   response body. Should be an identifier or slice. E.g _`Pet`_ or _`[Pet]`_
+///   response body. Should be an identifier, path or slice. E.g _`Pet`_, _`models::Pet`_ or _`[Pet]`_.
+///   Type can be wrapped with _`inline(...)`_ e.g. _`inline(Pet)`_ to render the component schema
+///   in place instead of referencing it.
 /// * `content_type = "..." | content_type = [...]` Can be used to override the default behaviour of auto resolving the content type
 ///   from the `body` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
 ///   [primitive Rust types][primitive] and _`application/json`_ for struct and complex enum types.
 ///   Content type can also be slice of **content_type** values if the endpoint support returning multiple
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
-///  _`json`_ and _`xml`_ formats.
+///  _`json`_ and _`xml`_ formats. Byte slice _`[u8]`_ body is documented as `binary` string when none of
+///  the content types is JSON.
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
 /// * `example = ...` Can be either `json!(...)` or literal str that can be parsed to json. `json!`
 ///   should be something that `serde_json::json!` can parse as a `serde_json::Value`. [^json]
@@ -340,34 +574,61 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 ///
 /// * `name` Name of the header. E.g. _`x-csrf-token`_
 /// * `type` Addtional type of the header value. Type is defined after `name` with equals sign before the type.
-///   Type should be identifer or slice of identifiers. E.g. _`String`_ or _`[String]`_
+///   Type should be identifer, slice or option of identifiers. E.g. _`String`_, _`[String]`_, _`Pet`_ or _`Option<i32>`_.
+///   Header typed as _`Option<...>`_ is declared not required and header with any other type is declared required.
 /// * `description = "..."` Can be used to define optional description for the response header as str.
+/// * `required` Define whether the header is always returned. By default resolved from the `type`.
+/// * `deprecated` Define whether the header is deprecated or not.
+/// * `style = ...` Defines how the header value is serialized by [`ParameterStyle`][style]. Only _`Simple`_
+///   is allowed for headers.
+/// * `explode` Defines whether _`object`_ typed header value is exploded to _`key=value`_ pairs.
+/// * `example = ...` Can be either `json!(...)` or literal str that can be parsed to json. `json!`
+///   should be something that `serde_json::json!` can parse as a `serde_json::Value`. [^json]
 ///
 /// **Header supported formats:**
 ///
 /// ```text
 /// ("x-csfr-token"),
 /// ("x-csrf-token" = String, description = "New csfr token"),
+/// ("x-rate-limit" = Option<i32>, description = "Requests left", deprecated, example = json!(10)),
 /// ```
 ///
 /// # Params Attributes
 ///
 /// * `name` _**Must be the first argument**_. Define the name for parameter.
-/// * `parameter_type` Define possible type for the parameter. Type should be an identifer, slice or option.
-///   E.g. _`String`_ or _`[String]`_ or _`Option<String>`_. Parameter type is placed after `name` with
+/// * `parameter_type` Define possible type for the parameter. Type should be an identifer, path, slice or option.
+///   E.g. _`String`_, _`models::PetFilter`_, _`[String]`_ or _`Option<String>`_. Parameter type is placed after `name` with
 ///   equals sign E.g. _`"id" = String`_
 /// * `in` _**Must be placed after name or parameter_type**_. Define the place of the parameter.
 ///   E.g. _`path, query, header, cookie`_
 /// * `deprecated` Define whether the parameter is deprecated or not.
 /// * `description = "..."` Define possible description for the parameter as str.
+/// * `style = ...` Defines how the parameter is serialized by [`ParameterStyle`][style]. Value is the name
+///   of the style variant E.g. _`Form`_, _`SpaceDelimited`_ or _`DeepObject`_.
+/// * `explode` Defines whether a separate parameter is created for each value of _`array`_ or _`object`_
+///   typed parameter.
+/// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ are allowed within the
+///   value of a query parameter.
+/// * `example = ...` Can be either `json!(...)` or literal str that can be parsed to json. `json!`
+///   should be something that `serde_json::json!` can parse as a `serde_json::Value`. [^json]
+/// * `content = "..."` Define the media type of a parameter value serialized as a whole e.g. JSON encoded
+///   query parameter. The `parameter_type` is then documented within the parameter _`content`_ with the
+///   given media type instead of the _`schema`_. E.g. _`content = "application/json"`_.
 ///
 /// **Params supports following representation formats:**
 ///
 /// ```text
 /// ("id" = String, path, deprecated, description = "Pet database id"),
 /// ("id", path, deprecated, description = "Pet database id"),
+/// ("tags" = [String], query, style = Form, explode, example = json!(["cat", "dog"])),
+/// ("filter" = PetFilter, query, style = DeepObject, explode),
+/// ("filter" = PetFilter, query, content = "application/json"),
 /// ```
 ///
+/// Array query parameter exploded as _`?tags=cat&tags=dog`_ is defined with _`style = Form, explode`_
+/// and the comma separated _`?tags=cat,dog`_ with _`style = Form, explode = false`_. Object query parameters
+/// such as _`?filter[name]=bob`_ used by e.g. **serde_qs** are defined with _`style = DeepObject, explode`_.
+///
 /// # Security Requirement Attributes
 ///
 /// * `name` Define the name for security requirement. This must match to name of existing
@@ -381,11 +642,16 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// (),
 /// ("name" = []),
 /// ("name" = ["scope1", "scope2"]),
+/// ("name" = ["scope1", "scope2"], "name2" = []),
 /// ```
 ///
 /// Leaving empty _`()`_ creates an empty [`SecurityRequirement`][security] this is useful when
 /// security requirement is optional for operation.
 ///
+/// Multiple comma separated names within same parentheses combine the security schemes to a single
+/// [`SecurityRequirement`][security] where all of them must be satisfied e.g. api key **and** oauth2
+/// scope. Separate parenthesized requirements are alternatives where only one must be satisfied.
+///
 /// # Examples
 ///
 /// Example with all possible arguments.
@@ -415,7 +681,8 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 ///    security(
 ///        (),
 ///        ("my_auth" = ["read:items", "edit:items"]),
-///        ("token_jwt" = [])
+///        ("token_jwt" = []),
+///        ("api_key" = [], "my_auth" = ["edit:items"])
 ///    )
 /// )]
 /// fn post_pet(pet: Pet) -> Pet {
@@ -533,6 +800,7 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// [security]: openapi/security/struct.SecurityRequirement.html
 /// [security_schema]: openapi/security/struct.SecuritySchema.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
+/// [style]: openapi/path/enum.ParameterStyle.html
 ///
 /// [^json]: **json** feature need to be enabled for `json!(...)` type to work.
 ///
@@ -593,7 +861,10 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// **Accepted argument attributes:**
 ///
 /// * `handlers(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
-/// * `components(...)`  List of [`Component`][component]s in OpenAPI schema.
+/// * `components(...)`  List of [`Component`][component]s in OpenAPI schema. Components can be given as
+///   path qualified types e.g. _`models::Pet`_. The component is registered with the name returned by
+///   [`Component::component_name`][component_name] e.g. the type name or the module path where the type
+///   is defined with **namespaced_components** feature.
 /// * `modifiers(...)` List of items implemeting [`Modify`][modify] trait for runtime OpenApi modification.
 ///   See the [trait documentation][modify] for more details.
 /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
@@ -665,6 +936,7 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// [openapi]: trait.OpenApi.html
 /// [openapi_struct]: openapi/struct.OpenApi.html
 /// [component]: derive.Component.html
+/// [component_name]: trait.Component.html#method.component_name
 /// [path]: attr.path.html
 /// [modify]: trait.Modify.html
 /// [info]: openapi/info/struct.Info.html
@@ -781,29 +1053,47 @@ impl ToTokens for Required {
 ///
 /// Supports formats:
 ///   * `type` type is just a simple type identifier
+///   * `path::to::type` type is a path qualified type
 ///   * `[type]` type is an array of types
 ///   * `Option<type>` type is option of type
 ///   * `Option<[type]>` type is an option of array of types
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Type {
-    ty: Ident,
+    ty: syn::Path,
     is_array: bool,
     is_option: bool,
+    is_inline: bool,
 }
 
 impl Type {
     #[cfg(feature = "actix_extras")]
     pub fn new(ident: Ident) -> Self {
         Self {
-            ty: ident,
+            ty: syn::Path::from(ident),
             is_array: false,
             is_option: false,
+            is_inline: false,
         }
     }
 }
 
 impl Parse for Type {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let is_inline_type = input.peek(syn::Ident)
+            && input.peek2(Paren)
+            && matches!(input.fork().parse::<Ident>(), Ok(ident) if ident == "inline");
+
+        if is_inline_type {
+            input.parse::<Ident>()?;
+            let group;
+            parenthesized!(group in input);
+
+            let mut ty = group.parse::<Type>()?;
+            ty.is_inline = true;
+
+            return Ok(ty);
+        }
+
         let mut is_array = false;
         let mut is_option = false;
 
@@ -811,40 +1101,98 @@ impl Parse for Type {
             is_array = true;
             let group;
             bracketed!(group in input);
-            group.parse::<Ident>()
+            group.parse::<syn::Path>()
         };
 
-        let ty = if input.peek(syn::Ident) {
-            let mut ident: Ident = input.parse()?;
+        let is_option_type = input.peek(syn::Ident)
+            && input.peek2(Token![<])
+            && matches!(input.fork().parse::<Ident>(), Ok(ident) if ident == "Option");
 
+        let ty = if is_option_type {
             // is option of type or [type]
-            if (ident == "Option" && input.peek(Token![<]))
-                && (input.peek2(syn::Ident) || input.peek2(Bracket))
-            {
-                is_option = true;
+            is_option = true;
 
-                input.parse::<Token![<]>()?;
+            input.parse::<Ident>()?;
+            input.parse::<Token![<]>()?;
+            let ty = if input.peek(Bracket) {
+                parse_array(input)?
+            } else {
+                input.parse::<syn::Path>()?
+            };
+            input.parse::<Token![>]>()?;
 
-                if input.peek(syn::Ident) {
-                    ident = input.parse::<Ident>()?;
-                } else {
-                    ident = parse_array(input)?;
-                }
-                input.parse::<Token![>]>()?;
-            }
-            Ok(ident)
-        } else {
+            Ok(ty)
+        } else if input.peek(Bracket) {
             parse_array(input)
+        } else {
+            input.parse::<syn::Path>()
         }?;
 
         Ok(Type {
             ty,
             is_array,
             is_option,
+            is_inline: false,
         })
     }
 }
 
+/// Resolves component of the given type with `utoipa::PartialComponent` implementation of the type
+/// if it exists. Otherwise the type is referenced by the name from `utoipa::Component::component_name`
+/// or by the name resolved from the type at runtime when the type does not define one.
+///
+/// Only plain type paths such as `Pet` or `models::Pet` fall back to the name of the type. Generic
+/// types, arrays and tuples must implement either of the traits since the name of e.g. a container
+/// does not name a component.
+fn resolve_partial_component(ty: &syn::Type) -> TokenStream2 {
+    if is_plain_type_path(ty) {
+        quote! {
+            {
+                use utoipa::__private::{
+                    ResolveComponentRef as _, ResolvePartialComponent as _, ResolveRef as _,
+                };
+                (&&&utoipa::__private::ComponentOf::<#ty>::new()).resolve()
+            }
+        }
+    } else {
+        quote! {
+            {
+                use utoipa::__private::{ResolveComponentRef as _, ResolvePartialComponent as _};
+                (&&&utoipa::__private::ComponentOf::<#ty>::new()).resolve()
+            }
+        }
+    }
+}
+
+/// Resolves inlined component of the given type with `utoipa::PartialComponent` implementation of
+/// the type if it exists. Otherwise the type must implement `utoipa::Component`.
+fn resolve_inline_component(ty: &syn::Type) -> TokenStream2 {
+    quote! {
+        {
+            use utoipa::__private::{
+                ResolveInlineComponent as _, ResolveInlinePartialComponent as _,
+            };
+            (&&utoipa::__private::ComponentOf::<#ty>::new()).resolve_inline()
+        }
+    }
+}
+
+/// Check whether type is a path without generic arguments e.g. `Pet` or `models::Pet`.
+fn is_plain_type_path(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => {
+            type_path.qself.is_none()
+                && type_path
+                    .path
+                    .segments
+                    .iter()
+                    .all(|segment| segment.arguments.is_empty())
+        }
+        syn::Type::Group(group) => is_plain_type_path(&group.elem),
+        _ => false,
+    }
+}
+
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct ExternalDocs {
@@ -905,6 +1253,8 @@ impl ToTokens for ExternalDocs {
 enum Example {
     String(TokenStream2),
     Json(TokenStream2),
+    /// Reference to function returning example value which is serialized with serde.
+    Fn(ExprPath),
 }
 
 impl ToTokens for Example {
@@ -914,6 +1264,9 @@ impl ToTokens for Example {
                 serde_json::json!(#json)
             }),
             Self::String(string) => tokens.extend(string.to_owned()),
+            Self::Fn(function) => tokens.extend(quote! {
+                serde_json::to_value(#function()).unwrap()
+            }),
         }
     }
 }
@@ -928,7 +1281,7 @@ mod parse_utils {
         parse::{Parse, ParseStream},
         punctuated::Punctuated,
         token::Comma,
-        Error, LitBool, LitStr, Token,
+        Error, ExprPath, Lit, LitBool, LitStr, Token,
     };
 
     use crate::Example;
@@ -1016,6 +1369,23 @@ mod parse_utils {
     }
 
     pub(crate) fn parse_next_lit_str_or_json_example(input: ParseStream, ident: &Ident) -> Example {
+        // function reference e.g. `Pet::example`, `Self::example` or `crate::examples::pet`,
+        // anything else than literal or `json!(...)`
+        let is_json = input.peek2(syn::Ident) && input.peek3(Token![!]);
+        if !input.peek2(Lit) && !is_json {
+            if !cfg!(feature = "json") {
+                abort! {ident, "example function reference requires json feature to be enabled"}
+            }
+
+            return Example::Fn(parse_next(input, || {
+                input.parse::<ExprPath>().unwrap_or_else(|error| {
+                    abort! {ident, "unparseable example, expected function reference, {}", error;
+                        help = "Try defining example = Pet::example"
+                    }
+                })
+            }));
+        }
+
         parse_next_lit_str_or_json(input, |error| {
             abort! {ident, "unparseable example, expected json!(), {}", error;
             help = r#"Try defining example = json!({{"key": "value"}})"#;
@@ -89,11 +89,22 @@ impl Parse for OpenApiAttr {
 
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Component {
-    ty: Ident,
+    ty: syn::Path,
     generics: Generics,
 }
 
 impl Component {
+    /// Type of the component with generics for calling the [`utoipa::Component`] functions.
+    fn to_type_tokens(&self) -> TokenStream {
+        let path = &self.ty;
+        if self.has_lifetime_generics() {
+            quote! { <#path> }
+        } else {
+            let (_, ty_generics, _) = self.generics.split_for_impl();
+            quote! { <#path #ty_generics> }
+        }
+    }
+
     fn has_lifetime_generics(&self) -> bool {
         self.generics
             .params
@@ -105,7 +116,7 @@ impl Component {
 impl Parse for Component {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Component {
-            ty: input.parse()?,
+            ty: input.call(syn::Path::parse_mod_style)?,
             generics: input.parse()?,
         })
     }
@@ -272,9 +283,10 @@ fn impl_components(
         let mut components_tokens = components.iter().fold(
             quote! { utoipa::openapi::ComponentsBuilder::new() },
             |mut schema, component| {
-                let ident = &component.ty;
-                let span = ident.span();
-                let component_name = &*ident.to_string();
+                let path = &component.ty;
+                let segments = path.segments.iter().map(|segment| &segment.ident);
+                let span = path.segments.last().unwrap().ident.span();
+                let ty = component.to_type_tokens();
                 let (_, ty_generics, _) = component.generics.split_for_impl();
 
                 let assert_ty_generics = if component.has_lifetime_generics() {
@@ -282,18 +294,25 @@ fn impl_components(
                 } else {
                     Some(ty_generics.to_token_stream())
                 };
-                let assert_component = format_ident!("_AssertComponent{}", component_name);
+                let assert_component = format_ident!(
+                    "_AssertComponent{}",
+                    segments
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("_")
+                );
                 tokens.extend(quote_spanned! {span=>
-                    struct #assert_component where #ident #assert_ty_generics: utoipa::Component;
+                    struct #assert_component where #path #assert_ty_generics: utoipa::Component;
                 });
 
-                let ty_generics = if component.has_lifetime_generics() {
-                    None
-                } else {
-                    Some(ty_generics)
-                };
                 schema.extend(quote! {
-                    .component(#component_name, <#ident #ty_generics>::component())
+                    .component(
+                        #ty::component_name().unwrap_or_else(|| {
+                            utoipa::__private::type_component_name::#ty().into()
+                        }),
+                        #ty::component(),
+                    )
+                    .components_from_iter(#ty::derived_components())
                 });
 
                 schema
@@ -1,4 +1,3 @@
-use std::fmt::Display;
 use std::{io::Error, str::FromStr};
 
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
@@ -6,7 +5,7 @@ use proc_macro_error::abort;
 use quote::{format_ident, quote, ToTokens};
 use syn::{parenthesized, parse::Parse, Token};
 
-use crate::{component_type::ComponentType, security_requirement::SecurityRequirementAttr, Array};
+use crate::{security_requirement::SecurityRequirementAttr, Array};
 use crate::{parse_utils, Deprecated};
 
 use self::{
@@ -493,17 +492,14 @@ impl ToTokens for Operation<'_> {
 }
 
 trait ContentTypeResolver {
-    fn resolve_content_type<'a, T: Display>(
-        &self,
-        content_type: Option<&'a String>,
-        component_type: &ComponentType<'a, T>,
-    ) -> &'a str {
+    /// Resolve content type of the body. Explicitly defined `content_type` is used if present.
+    /// Otherwise the content type is resolved from the `schema` variable at runtime. Primitive
+    /// types are treated as `text/plain` and other types as `application/json`.
+    fn resolve_content_type(&self, content_type: Option<&String>) -> TokenStream2 {
         if let Some(content_type) = content_type {
-            content_type
-        } else if component_type.is_primitive() {
-            "text/plain"
+            content_type.to_token_stream()
         } else {
-            "application/json"
+            quote! { utoipa::__private::default_content_type(&schema) }
         }
     }
 }
@@ -1,13 +1,14 @@
 use std::str::FromStr;
 
 use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_error::abort_call_site;
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     Error, LitStr, Token,
 };
 
-use crate::{parse_utils, Deprecated, Required, Type};
+use crate::{parse_utils, Deprecated, Example, Required, Type};
 
 use super::property::Property;
 
@@ -20,6 +21,8 @@ use super::property::Property;
 ///
 /// * ("id" = String, path, deprecated, description = "Users database id"),
 /// * ("id", path, deprecated, description = "Users database id"),
+/// * ("tags" = [String], query, style = Form, explode, allow_reserved, example = json!(["a", "b"])),
+/// * ("filter" = Filter, query, content = "application/json"),
 ///
 /// The `= String` type statement is optional if automatic resolvation is supported.
 #[derive(Default)]
@@ -30,6 +33,11 @@ pub struct Parameter {
     deprecated: bool,
     description: Option<String>,
     parameter_type: Option<Type>,
+    style: Option<ParameterStyle>,
+    explode: Option<bool>,
+    allow_reserved: Option<bool>,
+    example: Option<Example>,
+    content_type: Option<String>,
 }
 
 impl Parameter {
@@ -74,7 +82,7 @@ impl Parse for Parameter {
         }
 
         input.parse::<Token![,]>()?;
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: path, query, header, cookie, deprecated, description";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: path, query, header, cookie, deprecated, description, style, explode, allow_reserved, example, content";
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
@@ -96,6 +104,23 @@ impl Parse for Parameter {
                     parameter.description =
                         Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
                 }
+                "style" => {
+                    parameter.style =
+                        Some(parse_utils::parse_next(input, || input.parse::<ParameterStyle>())?)
+                }
+                "explode" => parameter.explode = Some(parse_utils::parse_bool_or_true(input)?),
+                "allow_reserved" => {
+                    parameter.allow_reserved = Some(parse_utils::parse_bool_or_true(input)?)
+                }
+                "example" => {
+                    parameter.example = Some(parse_utils::parse_next_lit_str_or_json_example(
+                        input, &ident,
+                    ))
+                }
+                "content" => {
+                    parameter.content_type =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?.value())
+                }
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -125,10 +150,41 @@ impl ToTokens for Parameter {
         }
 
         if let Some(ref parameter_type) = self.parameter_type {
-            let property = Property::new(parameter_type.is_array, &parameter_type.ty);
+            let property = Property::new(parameter_type);
             let required: Required = (!parameter_type.is_option).into();
 
-            tokens.extend(quote! { .schema(Some(#property)).required(#required) });
+            if let Some(ref content_type) = self.content_type {
+                tokens.extend(quote! {
+                    .content(#content_type, utoipa::openapi::Content::new(#property))
+                });
+            } else {
+                tokens.extend(quote! { .schema(Some(#property)) });
+            }
+            tokens.extend(quote! { .required(#required) });
+        }
+
+        if self.parameter_type.is_none() && self.content_type.is_some() {
+            abort_call_site!(
+                "parameter `{}` with content requires a type e.g. \"{}\" = Type",
+                name,
+                name
+            );
+        }
+
+        if let Some(ref style) = self.style {
+            tokens.extend(quote! { .style(Some(#style)) });
+        }
+
+        if let Some(ref explode) = self.explode {
+            tokens.extend(quote! { .explode(Some(#explode)) });
+        }
+
+        if let Some(ref allow_reserved) = self.allow_reserved {
+            tokens.extend(quote! { .allow_reserved(Some(#allow_reserved)) });
+        }
+
+        if let Some(ref example) = self.example {
+            tokens.extend(quote! { .example(Some(#example)) });
         }
     }
 }
@@ -178,3 +234,66 @@ impl ToTokens for ParameterIn {
         })
     }
 }
+
+/// Serialization style of the parameter. Values are parsed from the variant names
+/// such as `Form` or `DeepObject`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ParameterStyle {
+    Matrix,
+    Label,
+    Form,
+    Simple,
+    SpaceDelimited,
+    PipeDelimited,
+    DeepObject,
+}
+
+impl FromStr for ParameterStyle {
+    type Err = syn::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Matrix" => Ok(Self::Matrix),
+            "Label" => Ok(Self::Label),
+            "Form" => Ok(Self::Form),
+            "Simple" => Ok(Self::Simple),
+            "SpaceDelimited" => Ok(Self::SpaceDelimited),
+            "PipeDelimited" => Ok(Self::PipeDelimited),
+            "DeepObject" => Ok(Self::DeepObject),
+            _ => Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "unexpected style: {}, expected one of: Matrix, Label, Form, Simple, SpaceDelimited, PipeDelimited, DeepObject",
+                    s
+                ),
+            )),
+        }
+    }
+}
+
+impl Parse for ParameterStyle {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let style = input.parse::<Ident>()?;
+
+        style
+            .to_string()
+            .parse::<ParameterStyle>()
+            .map_err(|error| Error::new(style.span(), error))
+    }
+}
+
+impl ToTokens for ParameterStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Matrix => quote! { utoipa::openapi::path::ParameterStyle::Matrix },
+            Self::Label => quote! { utoipa::openapi::path::ParameterStyle::Label },
+            Self::Form => quote! { utoipa::openapi::path::ParameterStyle::Form },
+            Self::Simple => quote! { utoipa::openapi::path::ParameterStyle::Simple },
+            Self::SpaceDelimited => {
+                quote! { utoipa::openapi::path::ParameterStyle::SpaceDelimited }
+            }
+            Self::PipeDelimited => quote! { utoipa::openapi::path::ParameterStyle::PipeDelimited },
+            Self::DeepObject => quote! { utoipa::openapi::path::ParameterStyle::DeepObject },
+        })
+    }
+}
@@ -1,53 +1,66 @@
-use std::fmt::Display;
-
 use quote::{quote, ToTokens};
 
-use crate::component_type::{ComponentFormat, ComponentType};
+use crate::Type;
 
 /// Tokenizable object property. It is used as a object property for components or as property
 /// of request or response body or response header.
-pub(crate) struct Property<'a, T: Display> {
+pub(crate) struct Property<'a> {
     pub(crate) is_array: bool,
-    pub(crate) component_type: ComponentType<'a, T>,
+    path: &'a syn::Path,
+    is_inline: bool,
+    is_binary_content: bool,
 }
 
-impl<'a, T> Property<'a, T>
-where
-    T: Display,
-{
-    pub fn new(is_array: bool, ident: &'a T) -> Self {
+impl<'a> Property<'a> {
+    pub fn new(ty: &'a Type) -> Self {
         Self {
-            is_array,
-            component_type: ComponentType(ident),
+            is_array: ty.is_array,
+            path: &ty.ty,
+            is_inline: ty.is_inline,
+            is_binary_content: false,
         }
     }
+
+    /// Set content types of the body. Byte slice is documented as binary string when all of the
+    /// content types are explicitly defined and none of them is JSON e.g. _`application/octet-stream`_.
+    /// Otherwise it is documented as array of integers the same way it is serialized to JSON.
+    pub fn content_types<'c, I: IntoIterator<Item = &'c String>>(
+        mut self,
+        content_types: I,
+    ) -> Self {
+        let mut content_types = content_types.into_iter().peekable();
+
+        self.is_binary_content = content_types.peek().is_some()
+            && content_types.all(|content_type| !content_type.contains("json"));
+        self
+    }
 }
 
-impl<T> ToTokens for Property<'_, T>
-where
-    T: Display,
-{
+impl ToTokens for Property<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        if self.component_type.is_primitive() {
-            let component_type = &self.component_type;
-            let mut component = quote! {
-                utoipa::openapi::PropertyBuilder::new().component_type(#component_type)
-            };
-
-            let format = ComponentFormat(self.component_type.0);
-            if format.is_known_format() {
-                component.extend(quote! {
-                    .format(Some(#format))
-                })
-            }
-
-            tokens.extend(component);
-        } else {
-            let name = &*self.component_type.0.to_string();
+        if self.is_binary_content && self.is_array && self.path.is_ident("u8") {
+            tokens.extend(quote! {
+                utoipa::openapi::PropertyBuilder::new()
+                    .component_type(utoipa::openapi::ComponentType::String)
+                    .format(Some(utoipa::openapi::ComponentFormat::Binary))
+            });
+
+            return;
+        }
+
+        if self.is_inline {
+            let path = self.path;
 
             tokens.extend(quote! {
-                utoipa::openapi::Ref::from_component_name(#name)
+                <#path as utoipa::Component>::component()
             })
+        } else {
+            let ty = syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: self.path.clone(),
+            });
+
+            tokens.extend(crate::resolve_partial_component(&ty))
         };
 
         if self.is_array {
@@ -123,16 +123,18 @@ impl ContentTypeResolver for RequestBodyAttr {}
 impl ToTokens for RequestBodyAttr {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         if let Some(ref body_type) = self.content {
-            let property = Property::new(body_type.is_array, &body_type.ty);
+            let property = Property::new(body_type).content_types(&self.content_type);
 
-            let content_type =
-                self.resolve_content_type(self.content_type.as_ref(), &property.component_type);
+            let content_type = self.resolve_content_type(self.content_type.as_ref());
             let required: Required = (!body_type.is_option).into();
 
             tokens.extend(quote! {
-                utoipa::openapi::request_body::RequestBodyBuilder::new()
-                    .content(#content_type, utoipa::openapi::Content::new(#property))
-                    .required(Some(#required))
+                {
+                    let schema = utoipa::openapi::schema::Component::from(#property);
+                    utoipa::openapi::request_body::RequestBodyBuilder::new()
+                        .content(#content_type, utoipa::openapi::Content::new(schema))
+                        .required(Some(#required))
+                }
             });
         }
 
@@ -8,9 +8,9 @@ use syn::{
     Error, LitInt, LitStr, Token,
 };
 
-use crate::{parse_utils, Example, Type};
+use crate::{parse_utils, Deprecated, Example, Required, Type};
 
-use super::{property::Property, ContentTypeResolver};
+use super::{parameter::ParameterStyle, property::Property, ContentTypeResolver};
 
 /// Parsed representation of response attributes from `#[utoipa::path]` attribute.
 #[derive(Default)]
@@ -107,16 +107,13 @@ impl Parse for Response {
 impl ToTokens for Response {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let description = &self.description;
-        tokens.extend(quote! {
+        let mut response = quote! {
             utoipa::openapi::ResponseBuilder::new().description(#description)
-        });
-
-        if let Some(ref body_type) = self.response_type {
-            let body_ty = &body_type.ty;
+        };
 
-            let component = Property::new(body_type.is_array, body_ty);
+        if self.response_type.is_some() {
             let mut content = quote! {
-                utoipa::openapi::ContentBuilder::new().schema(#component)
+                utoipa::openapi::ContentBuilder::new().schema(schema.clone())
             };
 
             if let Some(ref example) = self.example {
@@ -127,13 +124,13 @@ impl ToTokens for Response {
 
             if let Some(content_types) = self.content_type.as_ref() {
                 content_types.iter().for_each(|content_type| {
-                    tokens.extend(quote! {
+                    response.extend(quote! {
                         .content(#content_type, #content.build())
                     })
                 })
             } else {
-                let default_type = self.resolve_content_type(None, &component.component_type);
-                tokens.extend(quote! {
+                let default_type = self.resolve_content_type(None);
+                response.extend(quote! {
                     .content(#default_type, #content.build())
                 });
             }
@@ -141,12 +138,25 @@ impl ToTokens for Response {
 
         self.headers.iter().for_each(|header| {
             let name = &header.name;
-            tokens.extend(quote! {
+            response.extend(quote! {
                 .header(#name, #header)
             })
         });
 
-        tokens.extend(quote! { .build() })
+        response.extend(quote! { .build() });
+
+        if let Some(ref body_type) = self.response_type {
+            let component =
+                Property::new(body_type).content_types(self.content_type.iter().flatten());
+            tokens.extend(quote! {
+                {
+                    let schema = utoipa::openapi::schema::Component::from(#component);
+                    #response
+                }
+            })
+        } else {
+            tokens.extend(response)
+        }
     }
 }
 
@@ -180,7 +190,15 @@ impl ToTokens for Responses<'_> {
 /// could be written as follows: `("x-my-header-name")`.
 ///
 /// The `type` can be any typical type supported as a header argument such as `String, i32, u64, bool` etc.
-/// and if not provided it will default to `String`.
+/// or a component reference such as `Pet`. If the type is wrapped in `Option<..>` the header is declared
+/// as not required. If not provided type will default to `String`.
+///
+/// Header also supports following optional attributes:
+/// * `required` Whether the header is always returned. By default resolved from the `type`.
+/// * `deprecated` Whether the header is deprecated.
+/// * `style = Simple` Serialization style of the header value. Headers only support `Simple` style.
+/// * `explode` Whether _`object`_ typed header value is exploded.
+/// * `example = ...` Literal str or `json!(...)` example of the header value.
 ///
 /// # Examples
 ///
@@ -227,16 +245,37 @@ impl ToTokens for Responses<'_> {
 ///     ]
 /// )]
 /// ```
+///
+/// Example of optional header with all attributes.
+/// ```text
+/// #[utoipa::path(
+///     ...
+///     responses = [
+///         (status = 200, description = "success response",
+///             headers = [
+///                 ("x-rate-limit" = Option<i32>, description = "Requests left", deprecated,
+///                     style = Simple, example = json!(10)),
+///             ]
+///         ),
+///     ]
+/// )]
+/// ```
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Header {
     name: String,
     value_type: Option<Type>,
     description: Option<String>,
+    required: Option<bool>,
+    deprecated: Option<bool>,
+    style: Option<ParameterStyle>,
+    explode: Option<bool>,
+    example: Option<Example>,
 }
 
 impl Parse for Header {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: description, required, deprecated, style, explode, example";
         let mut header = Header {
             name: input.parse::<LitStr>()?.value(),
             ..Default::default()
@@ -257,26 +296,43 @@ impl Parse for Header {
             input.parse::<Token![,]>()?;
         }
 
-        if input.peek(syn::Ident) {
-            input
-                .parse::<Ident>()
-                .map_err(|error| {
-                    Error::new(
-                        error.span(),
-                        format!("unexpected attribute, expected: description, {}", error),
-                    )
-                })
-                .and_then(|ident| {
-                    if ident != "description" {
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+            let attribute_name = &*ident.to_string();
+
+            match attribute_name {
+                "description" => {
+                    header.description = Some(parse_utils::parse_next_literal_str(input)?);
+                }
+                "required" => header.required = Some(parse_utils::parse_bool_or_true(input)?),
+                "deprecated" => header.deprecated = Some(parse_utils::parse_bool_or_true(input)?),
+                "style" => {
+                    let style = parse_utils::parse_next(input, || input.parse::<ParameterStyle>())?;
+                    if !matches!(style, ParameterStyle::Simple) {
                         return Err(Error::new(
                             ident.span(),
-                            "unexpected attribute, expected: description",
+                            "unexpected style, header only supports style: Simple",
                         ));
                     }
-                    Ok(ident)
-                })?;
-            input.parse::<Token![=]>()?;
-            header.description = Some(input.parse::<LitStr>()?.value());
+                    header.style = Some(style)
+                }
+                "explode" => header.explode = Some(parse_utils::parse_bool_or_true(input)?),
+                "example" => {
+                    header.example = Some(parse_utils::parse_next_lit_str_or_json_example(
+                        input, &ident,
+                    ))
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
         }
 
         Ok(header)
@@ -287,7 +343,7 @@ impl ToTokens for Header {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         if let Some(ref header_type) = self.value_type {
             // header property with custom type
-            let header_type = Property::new(header_type.is_array, &header_type.ty);
+            let header_type = Property::new(header_type);
 
             tokens.extend(quote! {
                 utoipa::openapi::HeaderBuilder::new().schema(#header_type)
@@ -305,6 +361,44 @@ impl ToTokens for Header {
             })
         }
 
+        // typed header is required unless it is Option<..> or explicitly stated otherwise
+        let required = self.required.or_else(|| {
+            self.value_type
+                .as_ref()
+                .map(|value_type| !value_type.is_option)
+        });
+        if let Some(required) = required {
+            let required: Required = required.into();
+            tokens.extend(quote! {
+                .required(Some(#required))
+            })
+        }
+
+        if let Some(deprecated) = self.deprecated {
+            let deprecated: Deprecated = deprecated.into();
+            tokens.extend(quote! {
+                .deprecated(Some(#deprecated))
+            })
+        }
+
+        if let Some(ref style) = self.style {
+            tokens.extend(quote! {
+                .style(Some(#style))
+            })
+        }
+
+        if let Some(explode) = self.explode {
+            tokens.extend(quote! {
+                .explode(Some(#explode))
+            })
+        }
+
+        if let Some(ref example) = self.example {
+            tokens.extend(quote! {
+                .example(Some(#example))
+            })
+        }
+
         tokens.extend(quote! { .build() })
     }
 }
@@ -13,47 +13,48 @@ use crate::Array;
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct SecurityRequirementAttr {
-    name: Option<String>,
-    scopes: Option<Vec<String>>,
+    schemes: Vec<(String, Vec<String>)>,
 }
 
 impl Parse for SecurityRequirementAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.is_empty() {
-            return Ok(Self {
-                ..Default::default()
-            });
+        let mut schemes = Vec::new();
+
+        while !input.is_empty() {
+            let name = input.parse::<LitStr>()?.value();
+            input.parse::<Token![=]>()?;
+
+            let scopes_stream;
+            bracketed!(scopes_stream in input);
+            let scopes = Punctuated::<LitStr, Comma>::parse_terminated(&scopes_stream)?
+                .iter()
+                .map(LitStr::value)
+                .collect::<Vec<_>>();
+
+            schemes.push((name, scopes));
+
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
         }
-        let name = input.parse::<LitStr>()?.value();
-        input.parse::<Token![=]>()?;
-
-        let scopes_stream;
-        bracketed!(scopes_stream in input);
-        let scopes = Punctuated::<LitStr, Comma>::parse_terminated(&scopes_stream)?
-            .iter()
-            .map(LitStr::value)
-            .collect::<Vec<_>>();
-
-        Ok(Self {
-            name: Some(name),
-            scopes: Some(scopes),
-        })
+
+        Ok(Self { schemes })
     }
 }
 
 impl ToTokens for SecurityRequirementAttr {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if let (Some(name), Some(scopes)) = (&self.name, &self.scopes) {
+        let schemes = self.schemes.iter().map(|(name, scopes)| {
             let scopes_array = scopes.iter().collect::<Array<&String>>();
             let scopes_len = scopes.len();
 
-            tokens.extend(quote! {
-                utoipa::openapi::security::SecurityRequirement::new::<&str, [&str; #scopes_len], &str>(#name, #scopes_array)
-            })
-        } else {
-            tokens.extend(quote! {
-                utoipa::openapi::security::SecurityRequirement::default()
-            })
-        }
+            quote! {
+                .add::<&str, [&str; #scopes_len], &str>(#name, #scopes_array)
+            }
+        });
+
+        tokens.extend(quote! {
+            utoipa::openapi::security::SecurityRequirement::default() #( #schemes )*
+        })
     }
 }